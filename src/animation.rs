@@ -0,0 +1,60 @@
+use crate::Easing;
+use kiss3d::nalgebra::{Point3, UnitQuaternion, Vector3};
+use trackball::Frame;
+
+/// Animation from one frame to another.
+#[derive(Debug, Clone)]
+pub(crate) struct Animation {
+	from: Frame<f32>,
+	to: Frame<f32>,
+	duration: f32,
+	easing: Easing,
+	time: f32,
+}
+
+impl Animation {
+	/// Animates from one frame to another within duration in seconds wrt easing.
+	pub fn new(from: Frame<f32>, to: Frame<f32>, duration: f32, easing: Easing) -> Self {
+		Self {
+			from,
+			to,
+			duration,
+			easing,
+			time: 0.0,
+		}
+	}
+	/// Advances by frame time `dt` in seconds.
+	///
+	/// Returns interpolated frame and whether the animation has finished.
+	pub fn compute(&mut self, dt: f32) -> (Frame<f32>, bool) {
+		self.time += dt;
+		let t = if self.duration > 0.0 {
+			(self.time / self.duration).min(1.0)
+		} else {
+			1.0
+		};
+		(
+			interpolate(&self.from, &self.to, self.easing.apply(t)),
+			t >= 1.0,
+		)
+	}
+}
+
+/// Interpolates frames by slerping their rotations and lerping their targets and distances.
+pub(crate) fn interpolate(a: &Frame<f32>, b: &Frame<f32>, t: f32) -> Frame<f32> {
+	let rot = rotation(a).slerp(&rotation(b), t);
+	let pos = a.target().coords.lerp(&b.target().coords, t).into();
+	let zat = a.distance() + (b.distance() - a.distance()) * t;
+	from_parts(pos, &rot, zat)
+}
+
+/// Eye rotation from camera to world space around target.
+pub(crate) fn rotation(frame: &Frame<f32>) -> UnitQuaternion<f32> {
+	frame.view().rotation.inverse()
+}
+
+/// Frame from target position, eye rotation, and target distance from eye.
+pub(crate) fn from_parts(pos: Point3<f32>, rot: &UnitQuaternion<f32>, zat: f32) -> Frame<f32> {
+	let eye = pos + rot * Vector3::z() * zat;
+	Frame::look_at(pos, &eye, &(rot * Vector3::y()))
+}
@@ -0,0 +1,50 @@
+use kiss3d::nalgebra::{Point2, Quaternion, UnitQuaternion, Vector3};
+use trackball::Image;
+
+/// Orbit algorithm mapping cursor or finger movement to rotations.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum OrbitAlgorithm {
+	/// Exponential map of the [`trackball`] crate.
+	///
+	/// Rotation angle is proportional to the movement's length regardless of where it occurs. The
+	/// closer the movement is to the trackball's border, the more it rolls about the view
+	/// direction. Default.
+	#[default]
+	Trackball,
+	/// Shoemake's arcball projecting positions onto a sphere fitting the screen.
+	///
+	/// Rotates by twice the angle between the projected positions making the resulting rotation
+	/// independent of the path taken. Positions outside the sphere are projected onto its rim
+	/// resulting in pure roll about the view direction at the screen edges.
+	Arcball,
+}
+
+/// Arcball operation handler.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Arcball {
+	vec: Option<Vector3<f32>>,
+}
+
+impl Arcball {
+	/// Computes rotation in camera space between previous and current position in screen space.
+	pub fn compute(&mut self, pos: Point2<f32>, max: Point2<f32>) -> Option<UnitQuaternion<f32>> {
+		let pos = Image::clamp_pos_wrt_max(&pos, &max);
+		let (pos, max) = Image::transform_pos_and_max_wrt_max(&pos, &max);
+		let pos = pos / max.x.min(max.y);
+		let len = pos.coords.norm_squared();
+		let vec = if len <= 1.0 {
+			pos.coords.push((1.0 - len).sqrt())
+		} else {
+			pos.coords.normalize().push(0.0)
+		};
+		let old = self.vec.replace(vec)?;
+		let rot = Quaternion::from_parts(old.dot(&vec), old.cross(&vec));
+		let rot = UnitQuaternion::try_new(rot, f32::EPSILON)?;
+		// Orbit camera in the opposite sense of the scene's apparent rotation.
+		Some(rot.inverse())
+	}
+	/// Discards previous position.
+	pub fn discard(&mut self) {
+		self.vec = None;
+	}
+}
@@ -0,0 +1,12 @@
+/// Boundary condition constraining the frame, see [`crate::Trackball::set_on_clamp_hit()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ClampKind {
+	/// Target distance from eye as bounded by [`crate::Trackball::clamp`],
+	/// [`crate::Trackball::ortho_extent_bounds()`], [`crate::Trackball::keep_beyond_near()`], or
+	/// [`crate::Trackball::collision_sphere()`].
+	Distance,
+	/// Target position as bounded by [`crate::Trackball::pan_bounds()`].
+	Pan,
+	/// Eye elevation around target as bounded by [`crate::Trackball::keep_above_ground()`].
+	Pitch,
+}
@@ -0,0 +1,33 @@
+use kiss3d::{
+	event::{Action, MouseButton},
+	window::Canvas,
+};
+
+/// Cursor and mouse button state of window queried and altered while handling events.
+///
+/// Implemented by [`Canvas`], decoupling event handlers from an open window, e.g., for testing.
+pub(crate) trait Cursor {
+	/// Whether mouse button is pressed.
+	fn get_mouse_button(&self, button: MouseButton) -> Action;
+	/// Hides or shows cursor.
+	fn hide_cursor(&self, hide: bool);
+	/// Grabs or releases cursor.
+	fn set_cursor_grab(&self, grab: bool);
+	/// Moves cursor to position in pixels of window.
+	fn set_cursor_position(&self, x: f64, y: f64);
+}
+
+impl Cursor for Canvas {
+	fn get_mouse_button(&self, button: MouseButton) -> Action {
+		Canvas::get_mouse_button(self, button)
+	}
+	fn hide_cursor(&self, hide: bool) {
+		Canvas::hide_cursor(self, hide);
+	}
+	fn set_cursor_grab(&self, grab: bool) {
+		Canvas::set_cursor_grab(self, grab);
+	}
+	fn set_cursor_position(&self, x: f64, y: f64) {
+		Canvas::set_cursor_position(self, x, y);
+	}
+}
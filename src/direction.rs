@@ -0,0 +1,38 @@
+use kiss3d::nalgebra::Vector2;
+
+/// Screen-space direction of keyboard operations, see [`crate::Input::orbit_key()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Direction {
+	/// Towards the left border.
+	Left,
+	/// Towards the right border.
+	Right,
+	/// Towards the top border.
+	Up,
+	/// Towards the bottom border.
+	Down,
+}
+
+impl Direction {
+	/// All directions in order of declaration.
+	pub const ALL: [Self; 4] = [Self::Left, Self::Right, Self::Up, Self::Down];
+
+	/// Unit vector in screen space with positive y-axis pointing up.
+	#[must_use]
+	pub fn vector(self) -> Vector2<f32> {
+		match self {
+			Self::Left => -Vector2::x(),
+			Self::Right => Vector2::x(),
+			Self::Up => Vector2::y(),
+			Self::Down => -Vector2::y(),
+		}
+	}
+	/// Sum of unit vectors of pressed directions indexed in order of [`Self::ALL`].
+	pub(crate) fn sum(pressed: [bool; 4]) -> Vector2<f32> {
+		Self::ALL
+			.into_iter()
+			.filter(|&dir| pressed[dir as usize])
+			.map(Self::vector)
+			.sum()
+	}
+}
@@ -0,0 +1,47 @@
+/// Easing function mapping linear progress of animations onto eased progress.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Easing {
+	/// Constant speed. Default.
+	#[default]
+	Linear,
+	/// Accelerates from rest via `t²`.
+	EaseIn,
+	/// Decelerates to rest via `1 - (1 - t)²`.
+	EaseOut,
+	/// Accelerates from rest until halfway and decelerates to rest afterwards, both quadratically.
+	EaseInOut,
+	/// Accelerates and decelerates via `3t² - 2t³`.
+	Smoothstep,
+}
+
+impl Easing {
+	/// All easings in order of declaration.
+	pub const ALL: [Self; 5] = [
+		Self::Linear,
+		Self::EaseIn,
+		Self::EaseOut,
+		Self::EaseInOut,
+		Self::Smoothstep,
+	];
+
+	/// Maps linear progress `t` from `0.0` to `1.0` onto eased progress.
+	///
+	/// Progress is clamped to `0.0..=1.0`, all easings map `0.0` to `0.0` and `1.0` to `1.0`.
+	#[must_use]
+	pub fn apply(self, t: f32) -> f32 {
+		let t = t.clamp(0.0, 1.0);
+		match self {
+			Self::Linear => t,
+			Self::EaseIn => t * t,
+			Self::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+			Self::EaseInOut => {
+				if t < 0.5 {
+					2.0 * t * t
+				} else {
+					1.0 - 2.0 * (1.0 - t) * (1.0 - t)
+				}
+			}
+			Self::Smoothstep => t * t * (3.0 - 2.0 * t),
+		}
+	}
+}
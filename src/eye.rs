@@ -0,0 +1,8 @@
+/// Eye of stereo pair, see [`crate::Trackball::upload_anaglyph()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Eye {
+	/// Left eye, e.g., rendered in red for red/cyan anaglyphs.
+	Left,
+	/// Right eye, e.g., rendered in cyan for red/cyan anaglyphs.
+	Right,
+}
@@ -0,0 +1,94 @@
+use kiss3d::nalgebra::{Unit, Vector3};
+
+/// Movement of free-fly camera mode in camera space, see [`crate::Trackball::set_fly_mode()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FlyMove {
+	/// Moves along view direction.
+	Forward,
+	/// Moves against view direction.
+	Backward,
+	/// Moves to the left.
+	Left,
+	/// Moves to the right.
+	Right,
+	/// Moves up along the camera's yaw axis.
+	Up,
+	/// Moves down along the camera's yaw axis.
+	Down,
+}
+
+impl FlyMove {
+	/// All movements in order of declaration.
+	pub const ALL: [Self; 6] = [
+		Self::Forward,
+		Self::Backward,
+		Self::Left,
+		Self::Right,
+		Self::Up,
+		Self::Down,
+	];
+
+	/// Unit direction in camera space.
+	#[must_use]
+	pub fn direction(self) -> Vector3<f32> {
+		match self {
+			Self::Forward => -Vector3::z(),
+			Self::Backward => Vector3::z(),
+			Self::Left => -Vector3::x(),
+			Self::Right => Vector3::x(),
+			Self::Up => Vector3::y(),
+			Self::Down => -Vector3::y(),
+		}
+	}
+}
+
+/// Free-fly camera mode operation handler.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FlyMode {
+	moves: [bool; 6],
+	sprint: bool,
+	vel: Vector3<f32>,
+}
+
+impl FlyMode {
+	/// Sets whether movement is active as long as its key is being pressed.
+	pub fn set(&mut self, mv: FlyMove, active: bool) {
+		self.moves[mv as usize] = active;
+	}
+	/// Sets whether sprinting as long as its key is being pressed.
+	pub fn set_sprint(&mut self, sprint: bool) {
+		self.sprint = sprint;
+	}
+	/// Computes displacement in camera space by advancing velocity for frame time `dt`.
+	///
+	/// Velocity ramps up to maximum speed, optionally multiplied by sprint, and down to rest at
+	/// rate of acceleration or instantly without.
+	pub fn compute(
+		&mut self,
+		dt: f32,
+		max_speed: f32,
+		acceleration: Option<f32>,
+		sprint: f32,
+	) -> Vector3<f32> {
+		let dir = FlyMove::ALL
+			.into_iter()
+			.filter(|&mv| self.moves[mv as usize])
+			.map(FlyMove::direction)
+			.sum::<Vector3<f32>>()
+			.try_normalize(f32::EPSILON)
+			.unwrap_or_default();
+		let vel = dir * max_speed * if self.sprint { sprint } else { 1.0 };
+		self.vel = match acceleration {
+			Some(acceleration) => {
+				let (dir, len) = Unit::new_and_get(vel - self.vel);
+				if len <= acceleration * dt {
+					vel
+				} else {
+					self.vel + dir.into_inner() * acceleration * dt
+				}
+			}
+			None => vel,
+		};
+		self.vel * dt
+	}
+}
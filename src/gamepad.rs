@@ -0,0 +1,67 @@
+use kiss3d::nalgebra::Vector2;
+
+/// Gamepad state polled from any backend, see [`crate::Trackball::handle_gamepad_config()`].
+///
+/// Stick axes range from `-1.0` to `1.0` with positive x-axis pointing right and positive y-axis
+/// pointing up, triggers range from `0.0` when released to `1.0` when fully pressed.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct GamepadInput {
+	/// Left stick, orbits eye around target.
+	pub left_stick: Vector2<f32>,
+	/// Right stick, slides target and eye.
+	pub right_stick: Vector2<f32>,
+	/// Left trigger, zooms out.
+	pub left_trigger: f32,
+	/// Right trigger, zooms in.
+	pub right_trigger: f32,
+}
+
+/// Gamepad mapping of sticks and triggers, see [`crate::Trackball::handle_gamepad_config()`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GamepadConfig {
+	/// Radial deadzone of sticks and deadzone of triggers. Default is `0.15`.
+	///
+	/// Deflections within it are ignored, the remaining range is rescaled to start at zero.
+	pub deadzone: f32,
+	/// Orbit speed in radians per second at full deflection per stick axis. Default is
+	/// `[π, π]`.
+	pub orbit_sensitivity: Vector2<f32>,
+	/// Slide speed in target distances per second at full deflection per stick axis. Default is
+	/// `[1.0, 1.0]`.
+	pub slide_sensitivity: Vector2<f32>,
+	/// Zoom speed as natural logarithm of distance ratio per second at full deflection. Default
+	/// is `1.0`.
+	pub zoom_sensitivity: f32,
+}
+
+impl Default for GamepadConfig {
+	fn default() -> Self {
+		Self {
+			deadzone: 0.15,
+			orbit_sensitivity: Vector2::repeat(core::f32::consts::PI),
+			slide_sensitivity: Vector2::repeat(1.0),
+			zoom_sensitivity: 1.0,
+		}
+	}
+}
+
+impl GamepadConfig {
+	/// Stick deflection with radial deadzone applied.
+	pub(crate) fn stick(&self, vec: Vector2<f32>) -> Vector2<f32> {
+		let norm = vec.norm().min(1.0);
+		if norm > self.deadzone {
+			vec.normalize() * (norm - self.deadzone) / (1.0 - self.deadzone)
+		} else {
+			Vector2::zeros()
+		}
+	}
+	/// Trigger deflection with deadzone applied.
+	pub(crate) fn trigger(&self, value: f32) -> f32 {
+		let value = value.clamp(0.0, 1.0);
+		if value > self.deadzone {
+			(value - self.deadzone) / (1.0 - self.deadzone)
+		} else {
+			0.0
+		}
+	}
+}
@@ -0,0 +1,12 @@
+/// Handedness of world coordinate system.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Handedness {
+	/// Right-handed coordinate system as assumed by [`kiss3d`]. Default.
+	#[default]
+	RightHanded,
+	/// Left-handed coordinate system with mirrored x-axis in camera space.
+	///
+	/// Renders left-handed scenes correctly without pre-transforming them. Since mirroring
+	/// reverses the winding order of triangles, front faces become back faces.
+	LeftHanded,
+}
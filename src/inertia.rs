@@ -0,0 +1,46 @@
+use kiss3d::nalgebra::Vector3;
+
+/// Inertia operation handler carrying motion on after release.
+///
+/// Motion is given as vector per frame, e.g., a scaled rotation axis.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Inertia {
+	accum: Vector3<f32>,
+	vel: Vector3<f32>,
+	spin: Option<Vector3<f32>>,
+}
+
+impl Inertia {
+	/// Accumulates motion of current frame while operating.
+	pub fn record(&mut self, vec: Vector3<f32>) {
+		self.accum += vec;
+	}
+	/// Computes velocity of motion accumulated within frame time `dt` in seconds.
+	///
+	/// Keeps accumulating motion into the next frame unless `dt` is positive.
+	pub fn sample(&mut self, dt: f32) {
+		if dt > 0.0 {
+			self.vel = self.accum / dt;
+			self.accum = Vector3::zeros();
+		}
+	}
+	/// Releases operation carrying on with velocity of last frame capped at maximum velocity.
+	pub fn release(&mut self, max: Option<f32>) {
+		let vel = max.map_or(self.vel, |max| self.vel.cap_magnitude(max));
+		self.spin = Some(vel).filter(|vel| vel.norm() > f32::EPSILON);
+		self.vel = Vector3::zeros();
+	}
+	/// Computes motion carried on within frame time `dt` and decays velocity wrt time constant
+	/// `damping` in seconds.
+	pub fn compute(&mut self, dt: f32, damping: f32) -> Option<Vector3<f32>> {
+		let spin = self.spin?;
+		let vec = spin * dt;
+		let spin = spin * (1.0 - crate::blend(damping, dt));
+		self.spin = Some(spin).filter(|spin| spin.norm() > 1e-4);
+		Some(vec)
+	}
+	/// Stops motion and forgets velocity.
+	pub fn discard(&mut self) {
+		*self = Self::default();
+	}
+}
@@ -1,3 +1,4 @@
+use crate::{Direction, FlyMove, StandardView};
 use core::marker::PhantomData;
 use kiss3d::{
 	event::{Key, Modifiers, MouseButton},
@@ -9,12 +10,31 @@ use kiss3d::{
 pub struct Input<N: Copy + RealField> {
 	phantom_data: PhantomData<N>,
 	first_key: Option<Key>,
+	first_look_sensitivity: N,
+	first_up_key: Option<Key>,
+	first_down_key: Option<Key>,
 	ortho_key: Option<Key>,
 	reset_key: Option<Key>,
+	reset_rotation_key: Option<Key>,
+	reset_zoom_key: Option<Key>,
+	zoom_in_key: Option<Key>,
+	zoom_out_key: Option<Key>,
+	auto_rotate_key: Option<Key>,
+	standard_view_keys: [Option<Key>; 7],
+	fly_key: Option<Key>,
+	fly_move_keys: [Option<Key>; 6],
+	fly_sprint_key: Option<Key>,
+	orbit_keys: [Option<Key>; 4],
+	pan_keys: [Option<Key>; 4],
+	next_bookmark_key: Option<Key>,
+	prev_bookmark_key: Option<Key>,
 	orbit_button: Option<MouseButton>,
 	orbit_modifiers: Option<Modifiers>,
 	slide_button: Option<MouseButton>,
 	slide_modifiers: Option<Modifiers>,
+	scale_button: Option<MouseButton>,
+	scale_modifiers: Option<Modifiers>,
+	focus_modifiers: Option<Modifiers>,
 }
 
 impl<N: Copy + RealField> Default for Input<N> {
@@ -22,17 +42,104 @@ impl<N: Copy + RealField> Default for Input<N> {
 		Self {
 			phantom_data: PhantomData,
 			first_key: Some(Key::LShift),
+			first_look_sensitivity: N::one(),
+			first_up_key: None,
+			first_down_key: None,
 			ortho_key: Some(Key::O),
 			reset_key: Some(Key::Return),
+			reset_rotation_key: None,
+			reset_zoom_key: None,
+			zoom_in_key: None,
+			zoom_out_key: None,
+			auto_rotate_key: None,
+			standard_view_keys: [None; 7],
+			fly_key: None,
+			fly_move_keys: [
+				Some(Key::W),
+				Some(Key::S),
+				Some(Key::A),
+				Some(Key::D),
+				Some(Key::E),
+				Some(Key::Q),
+			],
+			fly_sprint_key: Some(Key::LShift),
+			orbit_keys: [None; 4],
+			pan_keys: [None; 4],
+			next_bookmark_key: None,
+			prev_bookmark_key: None,
 			orbit_button: Some(MouseButton::Button1),
 			orbit_modifiers: None,
 			slide_button: Some(MouseButton::Button2),
 			slide_modifiers: None,
+			scale_button: None,
+			scale_modifiers: None,
+			focus_modifiers: None,
 		}
 	}
 }
 
 impl<N: Copy + RealField> Input<N> {
+	/// Navigation scheme of Blender.
+	///
+	/// Mouse                              | Action
+	/// ---------------------------------- | ------------------------------------
+	/// Middle Button Press + Drag         | Orbits around target.
+	/// Middle Button Press + Drag + Shift | Slides trackball on focus plane.
+	/// Middle Button Press + Drag + Ctrl  | Scales distance zooming in/out.
+	///
+	/// Other inputs are left at their defaults.
+	#[must_use]
+	pub fn blender() -> Self {
+		Self {
+			orbit_button: Some(MouseButton::Button3),
+			orbit_modifiers: Some(Modifiers::empty()),
+			slide_button: Some(MouseButton::Button3),
+			slide_modifiers: Some(Modifiers::Shift),
+			scale_button: Some(MouseButton::Button3),
+			scale_modifiers: Some(Modifiers::Control),
+			..Self::default()
+		}
+	}
+	/// Navigation scheme of Maya.
+	///
+	/// Mouse                             | Action
+	/// --------------------------------- | ------------------------------------
+	/// Left Button Press + Drag + Alt    | Orbits around target.
+	/// Middle Button Press + Drag + Alt  | Slides trackball on focus plane.
+	/// Right Button Press + Drag + Alt   | Scales distance zooming in/out.
+	///
+	/// Operations only engage while Alt is pressed and stop as soon as it is released. Other inputs
+	/// are left at their defaults.
+	#[must_use]
+	pub fn maya() -> Self {
+		Self {
+			orbit_button: Some(MouseButton::Button1),
+			orbit_modifiers: Some(Modifiers::Alt),
+			slide_button: Some(MouseButton::Button3),
+			slide_modifiers: Some(Modifiers::Alt),
+			scale_button: Some(MouseButton::Button2),
+			scale_modifiers: Some(Modifiers::Alt),
+			..Self::default()
+		}
+	}
+	/// Navigation scheme common to CAD applications.
+	///
+	/// Mouse                             | Action
+	/// --------------------------------- | ------------------------------------
+	/// Middle Button Press + Drag        | Orbits around target.
+	/// Middle Button Press + Drag + Ctrl | Slides trackball on focus plane.
+	///
+	/// Other inputs are left at their defaults.
+	#[must_use]
+	pub fn cad() -> Self {
+		Self {
+			orbit_button: Some(MouseButton::Button3),
+			orbit_modifiers: Some(Modifiers::empty()),
+			slide_button: Some(MouseButton::Button3),
+			slide_modifiers: Some(Modifiers::Control),
+			..Self::default()
+		}
+	}
 	/// Key used to enable first person view as long as being pressed.
 	#[must_use]
 	pub fn first_key(&self) -> Option<Key> {
@@ -44,6 +151,41 @@ impl<N: Copy + RealField> Input<N> {
 	pub fn rebind_first_key(&mut self, key: Option<Key>) {
 		self.first_key = key;
 	}
+	/// Look sensitivity of first person view.
+	#[must_use]
+	pub fn first_look_sensitivity(&self) -> N {
+		self.first_look_sensitivity
+	}
+	/// Sets look sensitivity of first person view.
+	///
+	/// Scales mouse or finger movement independent of orbit speed. Default is `1.0`.
+	pub fn set_first_look_sensitivity(&mut self, sensitivity: N) {
+		self.first_look_sensitivity = sensitivity;
+	}
+	/// Key used to move up in first person view as long as being pressed.
+	#[must_use]
+	pub fn first_up_key(&self) -> Option<Key> {
+		self.first_up_key
+	}
+	/// Sets key used to move up in first person view as long as being pressed.
+	///
+	/// Moves along the world-up axis, see [`crate::Trackball::set_first_speed()`]. Use `None` to
+	/// disable key. Default is `None`.
+	pub fn rebind_first_up_key(&mut self, key: Option<Key>) {
+		self.first_up_key = key;
+	}
+	/// Key used to move down in first person view as long as being pressed.
+	#[must_use]
+	pub fn first_down_key(&self) -> Option<Key> {
+		self.first_down_key
+	}
+	/// Sets key used to move down in first person view as long as being pressed.
+	///
+	/// Moves along the world-up axis, see [`crate::Trackball::set_first_speed()`]. Use `None` to
+	/// disable key. Default is `None`.
+	pub fn rebind_first_down_key(&mut self, key: Option<Key>) {
+		self.first_down_key = key;
+	}
 	/// Key used to switch between orthographic and perspective projection.
 	#[must_use]
 	pub fn ortho_key(&self) -> Option<Key> {
@@ -66,6 +208,153 @@ impl<N: Copy + RealField> Input<N> {
 	pub fn rebind_reset_key(&mut self, key: Option<Key>) {
 		self.reset_key = key;
 	}
+	/// Key used to reset camera rotation only.
+	#[must_use]
+	pub fn reset_rotation_key(&self) -> Option<Key> {
+		self.reset_rotation_key
+	}
+	/// Sets key used to reset camera rotation only.
+	///
+	/// Use `None` to disable key.
+	pub fn rebind_reset_rotation_key(&mut self, key: Option<Key>) {
+		self.reset_rotation_key = key;
+	}
+	/// Key used to reset camera zoom only.
+	#[must_use]
+	pub fn reset_zoom_key(&self) -> Option<Key> {
+		self.reset_zoom_key
+	}
+	/// Sets key used to reset camera zoom only.
+	///
+	/// Use `None` to disable key.
+	pub fn rebind_reset_zoom_key(&mut self, key: Option<Key>) {
+		self.reset_zoom_key = key;
+	}
+	/// Key used to zoom in as long as being pressed.
+	#[must_use]
+	pub fn zoom_in_key(&self) -> Option<Key> {
+		self.zoom_in_key
+	}
+	/// Sets key used to zoom in as long as being pressed.
+	///
+	/// Suggested key is [`Key::Equals`]. Use `None` to disable key. Default is `None`.
+	pub fn rebind_zoom_in_key(&mut self, key: Option<Key>) {
+		self.zoom_in_key = key;
+	}
+	/// Key used to zoom out as long as being pressed.
+	#[must_use]
+	pub fn zoom_out_key(&self) -> Option<Key> {
+		self.zoom_out_key
+	}
+	/// Sets key used to zoom out as long as being pressed.
+	///
+	/// Suggested key is [`Key::Minus`]. Use `None` to disable key. Default is `None`.
+	pub fn rebind_zoom_out_key(&mut self, key: Option<Key>) {
+		self.zoom_out_key = key;
+	}
+	/// Key used to toggle auto-rotation.
+	#[must_use]
+	pub fn auto_rotate_key(&self) -> Option<Key> {
+		self.auto_rotate_key
+	}
+	/// Sets key used to toggle auto-rotation.
+	///
+	/// Use `None` to disable key.
+	pub fn rebind_auto_rotate_key(&mut self, key: Option<Key>) {
+		self.auto_rotate_key = key;
+	}
+	/// Key used to switch to standard view.
+	#[must_use]
+	pub fn standard_view_key(&self, view: StandardView) -> Option<Key> {
+		self.standard_view_keys[view as usize]
+	}
+	/// Sets key used to switch to standard view.
+	///
+	/// CAD users expect number keys, e.g., [`Key::Key1`] to [`Key::Key6`] in order of
+	/// [`StandardView::ALL`]. Use `None` to disable key.
+	pub fn rebind_standard_view_key(&mut self, view: StandardView, key: Option<Key>) {
+		self.standard_view_keys[view as usize] = key;
+	}
+	/// Key used to recall next bookmark.
+	#[must_use]
+	pub fn next_bookmark_key(&self) -> Option<Key> {
+		self.next_bookmark_key
+	}
+	/// Sets key used to recall next bookmark, see [`crate::Trackball::next_bookmark()`].
+	///
+	/// Suggested key is [`Key::PageDown`]. Use `None` to disable key. Default is `None`.
+	pub fn rebind_next_bookmark_key(&mut self, key: Option<Key>) {
+		self.next_bookmark_key = key;
+	}
+	/// Key used to recall previous bookmark.
+	#[must_use]
+	pub fn prev_bookmark_key(&self) -> Option<Key> {
+		self.prev_bookmark_key
+	}
+	/// Sets key used to recall previous bookmark, see [`crate::Trackball::prev_bookmark()`].
+	///
+	/// Suggested key is [`Key::PageUp`]. Use `None` to disable key. Default is `None`.
+	pub fn rebind_prev_bookmark_key(&mut self, key: Option<Key>) {
+		self.prev_bookmark_key = key;
+	}
+	/// Key used to toggle free-fly camera mode.
+	#[must_use]
+	pub fn fly_key(&self) -> Option<Key> {
+		self.fly_key
+	}
+	/// Sets key used to toggle free-fly camera mode.
+	///
+	/// Use `None` to disable key. Default is `None`.
+	pub fn rebind_fly_key(&mut self, key: Option<Key>) {
+		self.fly_key = key;
+	}
+	/// Key used to move in free-fly camera mode as long as being pressed.
+	#[must_use]
+	pub fn fly_move_key(&self, mv: FlyMove) -> Option<Key> {
+		self.fly_move_keys[mv as usize]
+	}
+	/// Sets key used to move in free-fly camera mode as long as being pressed.
+	///
+	/// Default is W, S, A, D, E, and Q in order of [`FlyMove::ALL`]. Use `None` to disable key.
+	pub fn rebind_fly_move_key(&mut self, mv: FlyMove, key: Option<Key>) {
+		self.fly_move_keys[mv as usize] = key;
+	}
+	/// Key used to sprint in free-fly camera mode as long as being pressed.
+	#[must_use]
+	pub fn fly_sprint_key(&self) -> Option<Key> {
+		self.fly_sprint_key
+	}
+	/// Sets key used to sprint in free-fly camera mode as long as being pressed.
+	///
+	/// Takes precedence over [`Self::first_key()`] in free-fly camera mode. Use `None` to disable
+	/// key. Default is [`Key::LShift`].
+	pub fn rebind_fly_sprint_key(&mut self, key: Option<Key>) {
+		self.fly_sprint_key = key;
+	}
+	/// Key used to orbit camera in direction as long as being pressed.
+	#[must_use]
+	pub fn orbit_key(&self, dir: Direction) -> Option<Key> {
+		self.orbit_keys[dir as usize]
+	}
+	/// Sets key used to orbit camera in direction as long as being pressed.
+	///
+	/// Suggested keys are the arrow keys in order of [`Direction::ALL`]. Use `None` to disable key.
+	/// Default is `None`.
+	pub fn rebind_orbit_key(&mut self, dir: Direction, key: Option<Key>) {
+		self.orbit_keys[dir as usize] = key;
+	}
+	/// Key used to pan camera in direction as long as being pressed.
+	#[must_use]
+	pub fn pan_key(&self, dir: Direction) -> Option<Key> {
+		self.pan_keys[dir as usize]
+	}
+	/// Sets key used to pan camera in direction as long as being pressed.
+	///
+	/// Keys bound to [`Self::orbit_key()`] take precedence. Use `None` to disable key. Default is
+	/// `None`.
+	pub fn rebind_pan_key(&mut self, dir: Direction, key: Option<Key>) {
+		self.pan_keys[dir as usize] = key;
+	}
 	/// Button used to orbit camera.
 	#[must_use]
 	pub fn orbit_button(&self) -> Option<MouseButton> {
@@ -116,4 +405,44 @@ impl<N: Copy + RealField> Input<N> {
 	pub fn set_slide_modifiers(&mut self, modifiers: Option<Modifiers>) {
 		self.slide_modifiers = modifiers;
 	}
+	/// Button used to scale camera distance by dragging vertically.
+	#[must_use]
+	pub fn scale_button(&self) -> Option<MouseButton> {
+		self.scale_button
+	}
+	/// Sets button used to scale camera distance by dragging vertically.
+	///
+	/// Dragging up zooms in. Use `None` to disable button. Default is `None`.
+	pub fn rebind_scale_button(&mut self, button: Option<MouseButton>) {
+		self.scale_button = button;
+	}
+	/// Modifiers that must be pressed for scale to occur.
+	#[must_use]
+	pub fn scale_modifiers(&self) -> Option<Modifiers> {
+		self.scale_modifiers
+	}
+	/// Sets modifiers that must be pressed for scale to occur.
+	///
+	///   * If set to `None`, then pressing any modifier will not prevent scale.
+	///   * If different from `None`, scale will occur only if the exact specified set of modifiers
+	///     is pressed. In particular, if set to `Some(Modifiers::empty())`, scale will occur only
+	///     if no modifier is pressed.
+	pub fn set_scale_modifiers(&mut self, modifiers: Option<Modifiers>) {
+		self.scale_modifiers = modifiers;
+	}
+	/// Modifiers that must be pressed at release for focus to occur.
+	#[must_use]
+	pub fn focus_modifiers(&self) -> Option<Modifiers> {
+		self.focus_modifiers
+	}
+	/// Sets modifiers that must be pressed at release for focus to occur.
+	///
+	///   * If set to `None`, then pressing any modifier will not prevent focus.
+	///   * If different from `None`, focus will occur only if the exact specified set of modifiers
+	///     is pressed, e.g., `Some(Modifiers::Alt)` to require Alt+click.
+	///
+	/// See [`crate::Trackball::set_focus_on_release()`].
+	pub fn set_focus_modifiers(&mut self, modifiers: Option<Modifiers>) {
+		self.focus_modifiers = modifiers;
+	}
 }
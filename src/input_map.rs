@@ -0,0 +1,24 @@
+use crate::Trackball;
+use kiss3d::{event::WindowEvent, window::Canvas};
+
+/// Pluggable input mapping of events onto camera operations.
+///
+/// Custom input maps are chained in front of the default behavior, see
+/// [`Trackball::push_input_map()`], letting applications add their own gestures without
+/// replicating the built-in ones.
+pub trait InputMap {
+	/// Handles event and returns whether it was consumed.
+	///
+	/// A consumed event is neither passed to subsequent input maps nor to the default behavior.
+	fn handle(&mut self, trackball: &mut Trackball, canvas: &Canvas, event: &WindowEvent) -> bool;
+}
+
+/// Default input mapping wrt [`Trackball::input`], see [`Trackball::handle_default_event()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct DefaultInputMap;
+
+impl InputMap for DefaultInputMap {
+	fn handle(&mut self, trackball: &mut Trackball, canvas: &Canvas, event: &WindowEvent) -> bool {
+		trackball.handle_default_event(canvas, event)
+	}
+}
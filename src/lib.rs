@@ -10,10 +10,14 @@
 #![allow(clippy::collapsible_else_if)]
 #![no_std]
 
+extern crate alloc;
+
+use alloc::{collections::VecDeque, rc::Rc, string::String, vec::Vec};
+use core::cell::RefCell;
 use kiss3d::{
 	camera::Camera,
 	event::{Action, Key, Modifiers, MouseButton, TouchAction, WindowEvent},
-	nalgebra::{Isometry3, Matrix4, Point2, Point3, UnitQuaternion, Vector3},
+	nalgebra::{Isometry3, Matrix4, Point2, Point3, Unit, UnitQuaternion, Vector2, Vector3},
 	resource::ShaderUniform,
 	window::Canvas,
 };
@@ -22,8 +26,46 @@ use trackball::{Clamp, First, Fixed, Frame, Image, Orbit, Scale, Scene, Slide, T
 pub use kiss3d;
 pub use trackball;
 
+mod animation;
+mod arcball;
+mod clamp;
+mod cursor;
+mod direction;
+mod easing;
+mod eye;
+mod fly;
+mod gamepad;
+mod handedness;
+mod inertia;
 mod input;
+mod input_map;
+mod multi_view;
+mod smoothing;
+mod tap;
+#[cfg(test)]
+mod tests;
+mod view;
+
+pub use arcball::OrbitAlgorithm;
+pub use clamp::ClampKind;
+pub use direction::Direction;
+pub use easing::Easing;
+pub use eye::Eye;
+pub use fly::FlyMove;
+pub use gamepad::{GamepadConfig, GamepadInput};
+pub use handedness::Handedness;
 pub use input::*;
+pub use input_map::{DefaultInputMap, InputMap};
+pub use multi_view::MultiView;
+pub use view::*;
+
+use animation::{from_parts, rotation, Animation};
+use arcball::Arcball;
+use cursor::Cursor;
+use fly::FlyMode;
+use inertia::Inertia;
+use smoothing::Smoothing;
+use tap::Tap;
 
 /// Trackball camera mode.
 ///
@@ -34,7 +76,8 @@ pub use input::*;
 ///
 /// # Camera Input
 ///
-/// Following default inputs are defined which are customizable via [`Self::input`]:
+/// Following default inputs are defined which are customizable via [`Self::input`] or replaceable
+/// by navigation schemes like [`Input::blender()`], [`Input::maya()`], or [`Input::cad()`]:
 ///
 /// Mouse                       | Touch                          | Action
 /// --------------------------- | ------------------------------ | ---------------------------------
@@ -44,6 +87,7 @@ pub use input::*;
 /// Right Button Press + Drag   | Two-Finger + Drag              | Slides trackball on focus plane.
 /// Scroll In/Out               | Two-Finger + Pinch Out/In      | Scales distance zooming in/out.
 /// Left Button Press + Release | Any-Finger + Release           | Slides to cursor/finger position.
+///                             | Two-Finger + Tap               | Resets camera eye and target.
 ///
 /// Keyboard                    | Action
 /// --------------------------- | ---------------------------------------------------------
@@ -56,10 +100,24 @@ pub use input::*;
 /// minimum and maximum target distance from camera eye. Optionally, update the alignment to reset
 /// to when pressing [`Input::reset_key()`] via [`Self::reset`].
 ///
+/// Frame setters like [`Self::reset_frame()`], [`Self::set_standard_view()`], or
+/// [`Self::set_pose()`] apply all boundary conditions at once, i.e., [`Self::clamp`] and the
+/// optional ones like [`Self::pan_bounds()`], so [`Self::frame`] reads back clamped right away.
+/// Direct changes of [`Self::frame`] and relative motion like [`Self::orbit_delta()`] are
+/// clamped on the next recompute, see [`Self::update_dt()`].
+///
 /// # Camera Projection
 ///
 /// Adjust camera projection via [`Self::scene`] like setting field of view or clip plane distances.
+///
+/// # Thread Safety
+///
+/// As the camera may share its scene with other cameras and its input maps with the application
+/// via [`Rc`], see [`Self::set_shared_scene()`] and [`Self::push_input_map()`], it is neither
+/// [`Send`] nor [`Sync`]. Cloning a camera clones the handles, that is the clone shares the same
+/// scene and input maps.
 #[derive(Clone)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Trackball {
 	/// Input keys/buttons and their modifiers.
 	pub input: Input<f32>,
@@ -77,8 +135,111 @@ pub struct Trackball {
 	orbit: Orbit<f32>,
 	scale: Scale<f32>,
 	slide: Slide<f32>,
+	drag: Slide<f32>,
+	orbiting: bool,
+	sliding: bool,
+	scaling: bool,
+	dragged: bool,
 	touch: Touch<Option<u64>, f32>,
 	mouse: Option<Point2<f64>>,
+	up: Unit<Vector3<f32>>,
+	keep_above_ground: bool,
+	keep_beyond_near: bool,
+	ground_height: f32,
+	on_projection_changed: Option<fn(bool)>,
+	on_clamp_hit: Option<fn(ClampKind)>,
+	frame_filter: Option<fn(Frame<f32>) -> Frame<f32>>,
+	orbit_speed: f32,
+	keyboard_orbit_step: f32,
+	orbit_keys: [bool; 4],
+	orbit_distance_scaling: bool,
+	touch_roll_enabled: bool,
+	touch_orbit_enabled: bool,
+	touch_pan_threshold: f32,
+	touch_pinch_threshold: f32,
+	touch_accum: (Vector2<f32>, f32),
+	touch_engaged: [bool; 2],
+	slide_speed: f32,
+	keyboard_pan_speed: f32,
+	pan_keys: [bool; 4],
+	slide_threshold: f32,
+	slide_pending: Option<Point2<f32>>,
+	drag_threshold: f32,
+	drag_pending: Option<Point2<f32>>,
+	input_smoothing: Option<f32>,
+	smoothing: Smoothing,
+	follow_reaim: bool,
+	log_zoom: bool,
+	reversed_z: bool,
+	infinite_far: bool,
+	proj: Matrix4<f32>,
+	proj_view: Matrix4<f32>,
+	proj_view_inv: Matrix4<f32>,
+	auto_rotate: Option<f32>,
+	auto_rotate_toggle: Option<f32>,
+	auto_rotate_timeout: Option<f32>,
+	auto_rotate_paused: bool,
+	idle: f32,
+	pan_bounds: Option<(Point3<f32>, Point3<f32>)>,
+	ortho_extent_bounds: Option<(f32, f32)>,
+	link_projections: bool,
+	touchpad_scroll_pans: bool,
+	orbit_algorithm: OrbitAlgorithm,
+	arcball: Arcball,
+	zoom_smoothing: Option<f32>,
+	keyboard_zoom_speed: f32,
+	zoom_keys: [bool; 2],
+	zoom_pending: f32,
+	zoom_anchor: Point2<f32>,
+	clock: f32,
+	tap: Tap,
+	two_finger_tap: Option<fn(&mut Trackball)>,
+	tap_window: f32,
+	tap_tolerance: f32,
+	frame_time: f32,
+	stepped: bool,
+	animation: Option<Animation>,
+	standard_view_duration: Option<f32>,
+	bookmarks: Vec<(String, Frame<f32>)>,
+	bookmark: Option<usize>,
+	bookmark_duration: Option<f32>,
+	easing: Easing,
+	focus_on_release: bool,
+	double_click_window: Option<f32>,
+	double_click_tolerance: f32,
+	last_click: Option<(Point2<f32>, f32)>,
+	handedness: Handedness,
+	flip_y: bool,
+	fly: Option<FlyMode>,
+	fly_max_speed: f32,
+	fly_acceleration: Option<f32>,
+	fly_sprint: f32,
+	aspect_override: Option<f32>,
+	letterbox: bool,
+	projection_screen: Option<[Point3<f32>; 3]>,
+	viewport: Option<Point2<f32>>,
+	waypoints: VecDeque<(Frame<f32>, f32, Easing)>,
+	damping: f32,
+	orbit_inertia: Option<Inertia>,
+	pan_inertia: Option<Inertia>,
+	pan_damping: Option<f32>,
+	zoom_inertia: Option<Inertia>,
+	zoom_damping: Option<f32>,
+	zoom_scrolled: bool,
+	zoom_scrolling: bool,
+	max_inertia_velocity: Option<f32>,
+	first_speed: f32,
+	release_pending: bool,
+	first_vertical: [bool; 2],
+	walk_height: Option<f32>,
+	collision_sphere: Option<(Point3<f32>, f32)>,
+	orbit_around_cursor: bool,
+	orbit_pivot: Option<Point3<f32>>,
+	computed: Option<(Frame<f32>, Scene<f32>, Point2<f32>)>,
+	dirty: bool,
+	view_changed: bool,
+	shared_scene: Option<Rc<RefCell<Scene<f32>>>>,
+	input_maps: Vec<Rc<RefCell<dyn InputMap>>>,
 }
 
 impl Trackball {
@@ -89,11 +250,16 @@ impl Trackball {
 	///
 	/// **Note:** Argument order differs from cameras in [`kiss3d::camera`].
 	#[must_use]
+	#[allow(clippy::too_many_lines)]
 	pub fn new(target: Point3<f32>, eye: &Point3<f32>, up: &Vector3<f32>) -> Trackball {
 		let frame = Frame::look_at(target, eye, up);
 		let reset = frame.clone();
 		let scene = Scene::default();
-		let image = Image::new(&frame, &scene, Point2::new(800.0, 600.0));
+		let mut image = Image::new(&frame, &scene, Point2::new(800.0, 600.0));
+		image.set_compute(false, false);
+		let proj = *image.projection();
+		let proj_view = *image.transformation();
+		let proj_view_inv = *image.inverse_transformation();
 		Self {
 			input: Input::default(),
 			clamp: Clamp::default(),
@@ -105,8 +271,111 @@ impl Trackball {
 			orbit: Orbit::default(),
 			scale: Scale::default(),
 			slide: Slide::default(),
+			drag: Slide::default(),
+			orbiting: false,
+			sliding: false,
+			scaling: false,
+			dragged: false,
 			touch: Touch::default(),
 			mouse: Option::default(),
+			up: Unit::new_normalize(*up),
+			keep_above_ground: false,
+			keep_beyond_near: true,
+			ground_height: 0.0,
+			on_projection_changed: None,
+			on_clamp_hit: None,
+			frame_filter: None,
+			orbit_speed: 1.0,
+			keyboard_orbit_step: core::f32::consts::FRAC_PI_2,
+			orbit_keys: [false; 4],
+			orbit_distance_scaling: false,
+			touch_roll_enabled: true,
+			touch_orbit_enabled: true,
+			touch_pan_threshold: 0.0,
+			touch_pinch_threshold: 0.0,
+			touch_accum: (Vector2::zeros(), 0.0),
+			touch_engaged: [false; 2],
+			slide_speed: 1.0,
+			keyboard_pan_speed: 1.0,
+			pan_keys: [false; 4],
+			slide_threshold: 0.0,
+			slide_pending: None,
+			drag_threshold: 0.0,
+			drag_pending: None,
+			input_smoothing: None,
+			smoothing: Smoothing::default(),
+			follow_reaim: false,
+			log_zoom: false,
+			reversed_z: false,
+			infinite_far: false,
+			proj,
+			proj_view,
+			proj_view_inv,
+			auto_rotate: None,
+			auto_rotate_toggle: None,
+			auto_rotate_timeout: None,
+			auto_rotate_paused: false,
+			idle: 0.0,
+			pan_bounds: None,
+			ortho_extent_bounds: None,
+			link_projections: false,
+			touchpad_scroll_pans: false,
+			orbit_algorithm: OrbitAlgorithm::default(),
+			arcball: Arcball::default(),
+			zoom_smoothing: None,
+			keyboard_zoom_speed: 120.0,
+			zoom_keys: [false; 2],
+			zoom_pending: 0.0,
+			zoom_anchor: Point2::origin(),
+			clock: 0.0,
+			tap: Tap::default(),
+			two_finger_tap: Some(Self::reset_frame),
+			tap_window: 0.3,
+			tap_tolerance: 10.0,
+			frame_time: 1.0 / 60.0,
+			stepped: false,
+			animation: None,
+			standard_view_duration: None,
+			bookmarks: Vec::new(),
+			bookmark: None,
+			bookmark_duration: None,
+			easing: Easing::default(),
+			focus_on_release: true,
+			double_click_window: None,
+			double_click_tolerance: 5.0,
+			last_click: None,
+			handedness: Handedness::default(),
+			flip_y: false,
+			fly: None,
+			fly_max_speed: 1.0,
+			fly_acceleration: None,
+			fly_sprint: 2.0,
+			aspect_override: None,
+			letterbox: false,
+			projection_screen: None,
+			viewport: None,
+			waypoints: VecDeque::new(),
+			damping: 0.25,
+			orbit_inertia: None,
+			pan_inertia: None,
+			pan_damping: None,
+			zoom_inertia: None,
+			zoom_damping: None,
+			zoom_scrolled: false,
+			zoom_scrolling: false,
+			max_inertia_velocity: None,
+			first_speed: 1.0,
+			release_pending: false,
+			first_vertical: [false; 2],
+			walk_height: None,
+			collision_sphere: None,
+			orbit_around_cursor: false,
+			orbit_pivot: None,
+			computed: None,
+			dirty: true,
+			view_changed: true,
+			shared_scene: None,
+			input_maps: Vec::new(),
 		}
 	}
 	/// Like [`Self::new()`] but with custom viewing frustum.
@@ -128,244 +397,2750 @@ impl Trackball {
 		trackball.scene.set_clip_planes(znear, zfar);
 		trackball
 	}
-	fn handle_touch(
-		&mut self,
-		_canvas: &Canvas,
-		id: u64,
-		x: f64,
-		y: f64,
-		action: TouchAction,
-		_modifiers: Modifiers,
-	) {
-		#[allow(clippy::cast_possible_truncation)]
-		let pos = Point2::new(x as f32, y as f32);
-		match action {
-			TouchAction::Start | TouchAction::Move => {
-				if action == TouchAction::Start {
-					self.slide.discard();
-				}
-				if let Some((num, pos, rot, rat)) = self.touch.compute(Some(id), pos, 0) {
-					if self.first.enabled() {
-						if let Some(vec) = self.slide.compute(pos) {
-							if let Some((pitch, yaw, yaw_axis)) =
-								self.first.compute(&vec, self.image.max())
-							{
-								self.frame.look_around(pitch, yaw, yaw_axis);
-							}
-						}
-					} else {
-						if num == 1 {
-							if let Some(rot) = self.orbit.compute(&pos, self.image.max()) {
-								self.frame.local_orbit(&rot);
-							}
-						} else {
-							if let Some(vec) = self.slide.compute(pos) {
-								self.frame.local_slide(&self.image.project_vec(&vec));
-							}
-							if num == 2 {
-								let pos = self.image.project_pos(&pos);
-								let rot = UnitQuaternion::from_axis_angle(
-									&self.frame.local_roll_axis(),
-									rot,
-								);
-								self.frame.local_orbit_around(&rot, &pos);
-								self.frame.local_scale_around(rat, &pos);
-							}
-						}
-					}
-				}
-			}
-			TouchAction::End | TouchAction::Cancel => {
-				if let Some((_num, pos)) = self.touch.discard(Some(id)) {
-					self.frame.local_slide(&self.image.project_pos(&pos).coords);
-				}
-				self.orbit.discard();
-				self.slide.discard();
-			}
+	/// Like [`Self::new()`] but with scene shared with other cameras, see
+	/// [`Self::set_shared_scene()`].
+	///
+	/// **Note:** Argument order differs from cameras in [`kiss3d::camera`].
+	#[must_use]
+	pub fn with_shared_scene(
+		target: Point3<f32>,
+		eye: &Point3<f32>,
+		up: &Vector3<f32>,
+		scene: Rc<RefCell<Scene<f32>>>,
+	) -> Trackball {
+		let mut trackball = Self::new(target, eye, up);
+		trackball.set_shared_scene(Some(scene));
+		trackball
+	}
+	/// Creates camera from view transformation, target distance from eye, and scene.
+	///
+	/// The view transformation is from world to camera space like [`Self::to_isometry()`] and
+	/// the target is placed on the view direction at `distance` in front of the eye. The world-up
+	/// axis defaults to the positive y-axis, see [`Self::set_up_axis()`]. Composes with poses
+	/// computed by other camera systems.
+	#[must_use]
+	pub fn from_isometry(view: Isometry3<f32>, distance: f32, scene: Scene<f32>) -> Trackball {
+		let rot = view.rotation.inverse();
+		let eye = view.inverse_transform_point(&Point3::origin());
+		let target = eye - rot * Vector3::z() * distance;
+		let mut trackball = Self::new(target, &eye, &(rot * Vector3::y()));
+		trackball.up = Vector3::y_axis();
+		trackball.scene = scene;
+		trackball.compute();
+		trackball
+	}
+	/// View transformation from world to camera space, see [`Self::from_isometry()`].
+	#[must_use]
+	pub fn to_isometry(&self) -> Isometry3<f32> {
+		self.frame.view()
+	}
+	/// Camera orientation and eye position in world space.
+	///
+	/// The orientation rotates from camera to world space, that is the inverse rotation of
+	/// [`Self::to_isometry()`], e.g., for compactly serializing or synchronizing cameras over the
+	/// network.
+	#[must_use]
+	pub fn pose(&self) -> (UnitQuaternion<f32>, Vector3<f32>) {
+		(rotation(&self.frame), self.frame.eye().coords)
+	}
+	/// Sets camera orientation and eye position in world space, see [`Self::pose()`].
+	///
+	/// Derives the target at the current target distance along the new view direction and applies
+	/// the boundary conditions at once, e.g., for receiving synchronized cameras or loading
+	/// externally authored shots.
+	pub fn set_pose(&mut self, orientation: UnitQuaternion<f32>, eye: Vector3<f32>) {
+		let distance = self.frame.distance();
+		let target = Point3::from(eye) - orientation * Vector3::z() * distance;
+		self.set_clamped(from_parts(target, &orientation, distance));
+	}
+	/// World-up axis, see [`Self::set_up_axis()`].
+	#[must_use]
+	pub fn up_axis(&self) -> Unit<Vector3<f32>> {
+		self.up
+	}
+	/// Sets world-up axis as initially passed to [`Self::new()`].
+	///
+	/// The world-up axis is what spherical coordinates, the ground plane, and the yaw of first
+	/// person view and free-fly camera mode are measured against, e.g., switch between y-up and
+	/// z-up datasets. The current view is preserved. Particularly, an already-rolled frame keeps
+	/// its roll attitude, that is its [`Frame::yaw_axis()`] differs from the new world-up axis.
+	pub fn set_up_axis(&mut self, up: Vector3<f32>) {
+		self.up = Unit::new_normalize(up);
+		if self.first.enabled() {
+			self.first.capture(self.up);
 		}
+		self.dirty = true;
 	}
-	fn handle_mouse_button(
-		&mut self,
-		_canvas: &Canvas,
-		button: MouseButton,
-		action: Action,
-		_modifiers: Modifiers,
-	) {
-		if !self.first.enabled() {
-			if Some(button) == self.input.orbit_button() {
-				if action == Action::Press {
-					self.touch.compute(None, *self.image.pos(), 0);
-				} else {
-					self.orbit.discard();
-					if let Some((_num, pos)) = self.touch.discard(None) {
-						self.frame.local_slide(&self.image.project_pos(&pos).coords);
-					}
-				}
-			}
-			if Some(button) == self.input.slide_button() {
-				if action == Action::Press {
-					self.slide.compute(*self.image.pos());
-				} else {
-					self.slide.discard();
-				}
-			}
+	/// Spherical coordinates `(azimuth, elevation, distance)` of eye around target wrt
+	/// [`Self::up_axis()`], see [`Self::set_spherical()`].
+	#[must_use]
+	pub fn spherical(&self) -> (f32, f32, f32) {
+		let vec = self.up_rotation().inverse() * (self.frame.eye() - self.frame.target());
+		let azimuth = vec.x.atan2(vec.z);
+		let elevation = vec.y.atan2(vec.xz().norm());
+		(azimuth, elevation, vec.norm())
+	}
+	/// Sets eye position via spherical coordinates around target wrt [`Self::up_axis()`] and
+	/// applies boundary conditions.
+	///
+	/// Azimuth is measured about the world-up axis in the sense prescribed by the right-hand rule,
+	/// starting from the positive z-axis for a world-up axis of positive y-axis. Elevation is
+	/// measured from the plane orthogonal to the world-up axis, positive towards it.
+	pub fn set_spherical(&mut self, azimuth: f32, elevation: f32, distance: f32) {
+		let (sin_el, cos_el) = elevation.sin_cos();
+		let (sin_az, cos_az) = azimuth.sin_cos();
+		let vec = Vector3::new(cos_el * sin_az, sin_el, cos_el * cos_az) * distance;
+		let eye = self.frame.target() + self.up_rotation() * vec;
+		self.set_clamped(Frame::look_at(*self.frame.target(), &eye, &self.up));
+	}
+	/// Sets frame to standard view preserving target and distance and applies boundary
+	/// conditions.
+	pub fn set_standard_view(&mut self, view: StandardView) {
+		self.set_clamped(self.standard_view(view));
+	}
+	/// Animates frame to standard view within duration in seconds, see
+	/// [`Self::set_standard_view()`].
+	///
+	/// Slerps the rotation as advanced by [`Self::step()`].
+	pub fn animate_to_view(&mut self, view: StandardView, duration: f32) {
+		let frame = self.clamp.compute(self.standard_view(view), &self.scene);
+		self.animation = Some(Animation::new(
+			self.frame.clone(),
+			frame,
+			duration,
+			self.easing,
+		));
+	}
+	/// Duration in seconds of animating to standard view when pressing
+	/// [`Input::standard_view_key()`], see [`Self::set_standard_view_duration()`].
+	#[must_use]
+	pub fn standard_view_duration(&self) -> Option<f32> {
+		self.standard_view_duration
+	}
+	/// Sets duration in seconds of animating to standard view when pressing
+	/// [`Input::standard_view_key()`].
+	///
+	/// Use `None` to switch instantaneously. Default is `None`.
+	pub fn set_standard_view_duration(&mut self, duration: Option<f32>) {
+		self.standard_view_duration = duration;
+	}
+	/// Saves frame as bookmark of name.
+	///
+	/// Replaces the frame of an existing bookmark of the same name keeping its position in
+	/// insertion order, otherwise appends a new bookmark.
+	pub fn save_bookmark(&mut self, name: &str) {
+		let frame = self.frame.clone();
+		if let Some(index) = self.bookmark_index(name) {
+			self.bookmarks[index].1 = frame;
+		} else {
+			self.bookmarks.push((name.into(), frame));
 		}
 	}
-	fn handle_cursor_pos(&mut self, canvas: &Canvas, x: f64, y: f64, modifiers: Modifiers) {
-		let pos = Point2::new(x, y);
-		let is_eq = |old| old == pos || old == Point2::new(pos.x.floor(), pos.y.floor());
-		if self.mouse.replace(pos).map_or(true, is_eq) {
-			return;
+	/// Recalls bookmark of name and returns whether it exists.
+	///
+	/// Applies boundary conditions to the bookmarked frame, see [`Self::set_bookmark_duration()`].
+	pub fn recall_bookmark(&mut self, name: &str) -> bool {
+		let index = self.bookmark_index(name);
+		if let Some(index) = index {
+			self.recall_bookmark_at(index);
 		}
-		let (pos, max) = (pos.cast(), *self.image.max());
-		if self.first.enabled() {
-			if self.touch.fingers() == 0 {
-				if let Some(vec) = self.slide.compute(pos) {
-					canvas.hide_cursor(true);
-					canvas.set_cursor_grab(true);
-					if let Some((pitch, yaw, yaw_axis)) = self.first.compute(&vec, &max) {
-						self.frame.look_around(pitch, yaw, yaw_axis);
-					}
-				}
-				if pos.y <= 0.0 {
-					canvas.set_cursor_position(x, f64::from(max.y) - 2.0);
-					self.slide.discard();
-				}
-				if pos.x <= 0.0 {
-					canvas.set_cursor_position(f64::from(max.x) - 2.0, y);
-					self.slide.discard();
-				}
-				if pos.x >= max.x - 1.0 {
-					canvas.set_cursor_position(1.0, y);
-					self.slide.discard();
-				}
-				if pos.y >= max.y - 1.0 {
-					canvas.set_cursor_position(x, 1.0);
-					self.slide.discard();
-				}
-			}
-		} else {
-			self.image.set_pos(pos);
-			let orbit = self.input.orbit_button().map_or(false, |button| {
-				canvas.get_mouse_button(button) == Action::Press
-					&& self
-						.input
-						.orbit_modifiers()
-						.map_or(true, |m| m == modifiers)
-			});
-			let slide = self.input.slide_button().map_or(false, |button| {
-				canvas.get_mouse_button(button) == Action::Press
-					&& self
-						.input
-						.slide_modifiers()
-						.map_or(true, |m| m == modifiers)
-			});
-			if orbit && slide {
-				self.orbit.discard();
-				self.slide.discard();
-			}
-			if orbit {
-				if let Some(pos) = self.touch.compute(None, pos, 0).map(|val| val.1) {
-					if let Some(rot) = self.orbit.compute(&pos, &max) {
-						self.frame.local_orbit(&rot);
+		index.is_some()
+	}
+	/// Removes bookmark of name and returns whether it existed.
+	pub fn remove_bookmark(&mut self, name: &str) -> bool {
+		let index = self.bookmark_index(name);
+		if let Some(index) = index {
+			self.bookmarks.remove(index);
+			self.bookmark = self
+				.bookmark
+				.filter(|&current| current != index)
+				.map(|current| {
+					if current > index {
+						current - 1
+					} else {
+						current
 					}
-				}
-			}
-			if slide {
-				if let Some(vec) = self.slide.compute(pos) {
-					self.frame.local_slide(&self.image.project_vec(&vec));
-				}
-			}
+				});
 		}
+		index.is_some()
 	}
-	fn handle_scroll(&mut self, _canvas: &Canvas, _dx: f64, dy: f64, _modifiers: Modifiers) {
-		self.frame.local_scale_around(
-			#[allow(clippy::cast_possible_truncation)]
-			self.scale.compute(dy as f32),
-			&self.image.project_pos(self.image.pos()),
-		);
+	/// Names of bookmarks in insertion order.
+	pub fn bookmarks(&self) -> impl Iterator<Item = &str> + '_ {
+		self.bookmarks.iter().map(|(name, _frame)| name.as_str())
 	}
-	fn handle_key(&mut self, canvas: &Canvas, key: Key, action: Action, _modifiers: Modifiers) {
-		if Some(key) == self.input.first_key() {
-			let mid = self.image.max() * 0.5;
-			if action == Action::Press {
-				if !self.first.enabled() {
-					self.first.capture(self.frame.yaw_axis());
-					self.image.set_pos(mid);
-				}
-			} else {
-				self.slide.discard();
-				self.first.discard();
-				if self.touch.fingers() == 0 {
-					canvas.set_cursor_position(mid.x.into(), mid.y.into());
-					canvas.hide_cursor(false);
-					canvas.set_cursor_grab(false);
-				}
-			}
-		} else if action == Action::Press {
-			if Some(key) == self.input.ortho_key() {
-				self.scene.set_ortho(!self.scene.ortho());
-			} else if Some(key) == self.input.reset_key() {
-				self.frame = self.reset.clone();
-			}
+	/// Recalls bookmark following the one recalled last in insertion order.
+	///
+	/// Wraps around and starts with the first bookmark if none has been recalled yet. Invoked on
+	/// pressing [`Input::next_bookmark_key()`].
+	pub fn next_bookmark(&mut self) {
+		let len = self.bookmarks.len();
+		if len > 0 {
+			let index = self.bookmark.map_or(0, |index| (index + 1) % len);
+			self.recall_bookmark_at(index);
 		}
 	}
-	fn handle_framebuffer_size(&mut self, _canvas: &Canvas, w: u32, h: u32) {
-		self.image.set_max(Point2::new(w, h).cast());
+	/// Recalls bookmark preceding the one recalled last in insertion order.
+	///
+	/// Wraps around and starts with the last bookmark if none has been recalled yet. Invoked on
+	/// pressing [`Input::prev_bookmark_key()`].
+	pub fn prev_bookmark(&mut self) {
+		let len = self.bookmarks.len();
+		if len > 0 {
+			let index = self
+				.bookmark
+				.map_or(len - 1, |index| (index + len - 1) % len);
+			self.recall_bookmark_at(index);
+		}
 	}
-}
-
-impl Camera for Trackball {
-	fn clip_planes(&self) -> (f32, f32) {
-		self.scene.clip_planes(self.frame.distance())
+	/// Duration in seconds of animating to recalled bookmarks, see
+	/// [`Self::set_bookmark_duration()`].
+	#[must_use]
+	pub fn bookmark_duration(&self) -> Option<f32> {
+		self.bookmark_duration
 	}
-	fn view_transform(&self) -> Isometry3<f32> {
-		*self.image.view_isometry()
+	/// Sets duration in seconds of animating to recalled bookmarks.
+	///
+	/// Use `None` to switch instantaneously. Default is `None`.
+	pub fn set_bookmark_duration(&mut self, duration: Option<f32>) {
+		self.bookmark_duration = duration;
 	}
-	fn eye(&self) -> Point3<f32> {
-		self.frame.eye()
+	/// Index of bookmark of name.
+	fn bookmark_index(&self, name: &str) -> Option<usize> {
+		self.bookmarks
+			.iter()
+			.position(|(other, _frame)| other == name)
 	}
-	fn handle_event(&mut self, canvas: &Canvas, event: &WindowEvent) {
-		match *event {
-			WindowEvent::Touch(id, x, y, action, modifiers) => {
-				self.handle_touch(canvas, id, x, y, action, modifiers);
-			}
-			WindowEvent::MouseButton(button, action, modifiers) => {
-				self.handle_mouse_button(canvas, button, action, modifiers);
-			}
-			WindowEvent::CursorPos(x, y, modifiers) => {
-				self.handle_cursor_pos(canvas, x, y, modifiers);
-			}
-			WindowEvent::Scroll(dx, dy, modifiers) => {
-				self.handle_scroll(canvas, dx, dy, modifiers);
-			}
-			WindowEvent::Key(key, action, modifiers) => {
-				self.handle_key(canvas, key, action, modifiers);
-			}
-			WindowEvent::FramebufferSize(w, h) => {
-				self.handle_framebuffer_size(canvas, w, h);
-			}
-			_ => {}
+	/// Recalls bookmark at index wrt bookmark duration.
+	fn recall_bookmark_at(&mut self, index: usize) {
+		self.bookmark = Some(index);
+		let frame = self
+			.clamp
+			.compute(self.bookmarks[index].1.clone(), &self.scene);
+		if let Some(duration) = self.bookmark_duration {
+			self.animation = Some(Animation::new(
+				self.frame.clone(),
+				frame,
+				duration,
+				self.easing,
+			));
+		} else {
+			self.set_clamped(frame);
 		}
 	}
-	#[inline]
-	fn upload(
-		&self,
-		_: usize,
-		proj: &mut ShaderUniform<Matrix4<f32>>,
-		view: &mut ShaderUniform<Matrix4<f32>>,
+	/// Animates eye position inclusive its roll attitude and target position within duration in
+	/// seconds wrt [`Self::easing()`].
+	///
+	/// Takes the same arguments as [`Self::new()`], e.g., to smoothly go to a saved view. Slerps
+	/// the rotation derived from the up vector and lerps target and distance as advanced by
+	/// [`Self::step()`] and applies [`Self::clamp`] to the final frame.
+	pub fn transition_to(
+		&mut self,
+		target: Point3<f32>,
+		eye: Point3<f32>,
+		up: Vector3<f32>,
+		duration: f32,
 	) {
-		proj.upload(self.image.projection());
-		view.upload(self.image.view());
+		let frame = Frame::look_at(target, &eye, &up);
+		let frame = self.clamp.compute(frame, &self.scene);
+		self.animation = Some(Animation::new(
+			self.frame.clone(),
+			frame,
+			duration,
+			self.easing,
+		));
 	}
-	fn transformation(&self) -> Matrix4<f32> {
-		*self.image.transformation()
+	/// Easing of animations, see [`Self::set_easing()`].
+	#[must_use]
+	pub fn easing(&self) -> Easing {
+		self.easing
 	}
-	fn inverse_transformation(&self) -> Matrix4<f32> {
-		*self.image.inverse_transformation()
+	/// Sets easing of animations not taking an explicit one like [`Self::animate_to_view()`].
+	///
+	/// Default is [`Easing::Linear`].
+	pub fn set_easing(&mut self, easing: Easing) {
+		self.easing = easing;
 	}
-	fn update(&mut self, _: &Canvas) {
-		self.frame = self.clamp.compute(self.frame.clone(), &self.scene);
-		self.image.compute(self.frame.clone(), self.scene.clone());
+	/// Frame of standard view preserving target and distance.
+	fn standard_view(&self, view: StandardView) -> Frame<f32> {
+		let (eye, up) = view.eye_and_up();
+		let rot = self.up_rotation();
+		let target = *self.frame.target();
+		let eye = target + rot * eye * self.frame.distance();
+		Frame::look_at(target, &eye, &(rot * up))
+	}
+	/// Sets frame to [`StandardView::Front`], see [`Self::set_standard_view()`].
+	pub fn front(&mut self) {
+		self.set_standard_view(StandardView::Front);
+	}
+	/// Sets frame to [`StandardView::Back`], see [`Self::set_standard_view()`].
+	pub fn back(&mut self) {
+		self.set_standard_view(StandardView::Back);
+	}
+	/// Sets frame to [`StandardView::Left`], see [`Self::set_standard_view()`].
+	pub fn left(&mut self) {
+		self.set_standard_view(StandardView::Left);
+	}
+	/// Sets frame to [`StandardView::Right`], see [`Self::set_standard_view()`].
+	pub fn right(&mut self) {
+		self.set_standard_view(StandardView::Right);
+	}
+	/// Sets frame to [`StandardView::Top`], see [`Self::set_standard_view()`].
+	pub fn top(&mut self) {
+		self.set_standard_view(StandardView::Top);
+	}
+	/// Sets frame to [`StandardView::Bottom`], see [`Self::set_standard_view()`].
+	pub fn bottom(&mut self) {
+		self.set_standard_view(StandardView::Bottom);
+	}
+	/// Sets frame to [`StandardView::Iso`], see [`Self::set_standard_view()`].
+	pub fn iso(&mut self) {
+		self.set_standard_view(StandardView::Iso);
+	}
+	/// Whether to keep eye at or above ground plane, see [`Self::set_keep_above_ground()`].
+	#[must_use]
+	pub fn keep_above_ground(&self) -> bool {
+		self.keep_above_ground
+	}
+	/// Sets whether to keep eye at or above ground plane.
+	///
+	/// The ground plane is orthogonal to [`Self::up_axis()`] at [`Self::ground_height()`].
+	/// Whenever the eye would drop below it, its elevation around the target is clamped
+	/// accordingly. Default is `false`.
+	pub fn set_keep_above_ground(&mut self, keep_above_ground: bool) {
+		self.keep_above_ground = keep_above_ground;
+		self.dirty = true;
+	}
+	/// Whether to keep target beyond near clip plane, see [`Self::set_keep_beyond_near()`].
+	#[must_use]
+	pub fn keep_beyond_near(&self) -> bool {
+		self.keep_beyond_near
+	}
+	/// Sets whether to keep target beyond near clip plane.
+	///
+	/// Whenever zooming in, interactively or programmatically, would move the near clip plane
+	/// through the target, the target distance is clamped slightly beyond the near clip plane
+	/// instead. Has no effect in object inspection mode, see [`Scene::scale()`], as its near clip
+	/// plane is measured from target and hence scales along. Enabled by default as the near clip
+	/// plane measured from eye would otherwise cut through the target. Default is `true`.
+	pub fn set_keep_beyond_near(&mut self, keep_beyond_near: bool) {
+		self.keep_beyond_near = keep_beyond_near;
+		self.dirty = true;
+	}
+	/// Sphere the eye is kept outside of, see [`Self::set_collision_sphere()`].
+	#[must_use]
+	pub fn collision_sphere(&self) -> Option<(Point3<f32>, f32)> {
+		self.collision_sphere
+	}
+	/// Sets sphere of center and radius the eye is kept outside of, e.g., to not enter a model.
+	///
+	/// Whenever zooming or sliding would move the eye inside, it is clamped to the sphere's
+	/// surface along the view ray preserving target and rotation. Applied after [`Self::clamp`].
+	/// Default is none, see [`Self::clear_collision_sphere()`].
+	pub fn set_collision_sphere(&mut self, center: Point3<f32>, radius: f32) {
+		self.collision_sphere = Some((center, radius));
+		self.dirty = true;
+	}
+	/// Disables collision, see [`Self::set_collision_sphere()`].
+	pub fn clear_collision_sphere(&mut self) {
+		self.collision_sphere = None;
+		self.dirty = true;
+	}
+	/// Height of ground plane along world-up axis, see [`Self::set_keep_above_ground()`].
+	#[must_use]
+	pub fn ground_height(&self) -> f32 {
+		self.ground_height
+	}
+	/// Sets height of ground plane along world-up axis, see [`Self::set_keep_above_ground()`].
+	///
+	/// Default is `0.0`.
+	pub fn set_ground_height(&mut self, ground_height: f32) {
+		self.ground_height = ground_height;
+		self.dirty = true;
+	}
+	/// Whether projection is orthographic instead of perspective, see [`Self::set_ortho()`].
+	#[must_use]
+	pub fn is_ortho(&self) -> bool {
+		self.scene.ortho()
+	}
+	/// Sets whether projection is orthographic instead of perspective.
+	///
+	/// Delegates to [`Scene::set_ortho()`] taking effect on next update. Other than
+	/// [`Input::ortho_key()`], it does not invoke [`Self::on_projection_changed()`]. Default is
+	/// `false`.
+	pub fn set_ortho(&mut self, ortho: bool) {
+		self.scene.set_ortho(ortho);
+		self.dirty = true;
+	}
+	/// Callback invoked whenever [`Input::ortho_key()`] switches the projection mode.
+	#[must_use]
+	pub fn on_projection_changed(&self) -> Option<fn(bool)> {
+		self.on_projection_changed
+	}
+	/// Sets callback invoked whenever [`Input::ortho_key()`] switches the projection mode.
+	///
+	/// The callback is passed the new [`Scene::ortho()`] state. Use `None` to disable callback.
+	pub fn set_on_projection_changed(&mut self, callback: Option<fn(bool)>) {
+		self.on_projection_changed = callback;
+	}
+	/// Callback invoked whenever a boundary condition constrained the frame, see
+	/// [`Self::set_on_clamp_hit()`].
+	#[must_use]
+	pub fn on_clamp_hit(&self) -> Option<fn(ClampKind)> {
+		self.on_clamp_hit
+	}
+	/// Sets callback invoked whenever a boundary condition constrained the frame.
+	///
+	/// The callback is passed the [`ClampKind`] of each boundary condition which actually changed
+	/// the frame when computing it, e.g., to flash a maximum zoom indicator. Use `None` to disable
+	/// callback.
+	pub fn set_on_clamp_hit(&mut self, callback: Option<fn(ClampKind)>) {
+		self.on_clamp_hit = callback;
+	}
+	/// Custom boundary condition post-processing frame, see [`Self::set_frame_filter()`].
+	#[must_use]
+	pub fn frame_filter(&self) -> Option<fn(Frame<f32>) -> Frame<f32>> {
+		self.frame_filter
+	}
+	/// Sets custom boundary condition post-processing frame.
+	///
+	/// The filter is applied on update after all built-in boundary conditions and before
+	/// computing the image, e.g., to snap the target onto a path. As the image is only
+	/// recomputed when the frame changed, the filter should be idempotent. Use `None` to disable
+	/// filter. Default is `None`.
+	pub fn set_frame_filter(&mut self, filter: Option<fn(Frame<f32>) -> Frame<f32>>) {
+		self.frame_filter = filter;
+		self.dirty = true;
+	}
+	/// Whether view or projection has been recomputed since the start of the last step.
+	///
+	/// Lets render loops skip rendering frames while the camera rests, e.g., to save battery. The
+	/// flag is reset at the start of each [`Self::step()`] and set whenever the image is
+	/// recomputed. Hence, after [`Self::update_dt()`] it tells whether to render the frame before
+	/// rendering it. It is `true` before the first step.
+	#[must_use]
+	pub fn view_changed(&self) -> bool {
+		self.view_changed
+	}
+	/// Forces recomputing boundary conditions and image on next update.
+	///
+	/// The image is only recomputed when [`Self::frame`], [`Self::scene`], or the image size
+	/// changed since the last update. Invoke this after replacing [`Self::clamp`].
+	pub fn mark_dirty(&mut self) {
+		self.dirty = true;
+	}
+	/// Resets frame to [`Self::reset`] and applies boundary conditions.
+	pub fn reset_frame(&mut self) {
+		self.set_clamped(self.reset.clone());
+	}
+	/// Resets rotation to [`Self::reset`] preserving target and distance and applies boundary
+	/// conditions.
+	pub fn reset_rotation(&mut self) {
+		let mut frame = self.reset.clone();
+		frame.slide(&(self.frame.target() - self.reset.target()));
+		frame.set_distance(self.frame.distance());
+		self.set_clamped(frame);
+	}
+	/// Resets distance to [`Self::reset`] preserving target and rotation and applies boundary
+	/// conditions.
+	pub fn reset_zoom(&mut self) {
+		let mut frame = self.frame.clone();
+		frame.set_distance(self.reset.distance());
+		self.set_clamped(frame);
+	}
+	/// Interpolates frames at parameter `t` from `0.0` at `a` to `1.0` at `b`.
+	///
+	/// Slerps the rotation and lerps target and distance, so the eye follows the slerped rotation
+	/// around the lerped target. Same interpolation as used by animations like
+	/// [`Self::animate_to_view()`] but for building custom camera paths.
+	#[must_use]
+	pub fn interpolate(a: &Frame<f32>, b: &Frame<f32>, t: f32) -> Frame<f32> {
+		animation::interpolate(a, b, t)
+	}
+	/// Enqueues waypoint frame to animate to within duration in seconds wrt easing.
+	///
+	/// Waypoints are visited in sequence as advanced by [`Self::step()`], each segment
+	/// interpolating from the frame where the previous one ended, see [`Self::interpolate()`].
+	pub fn push_waypoint(&mut self, frame: Frame<f32>, duration: f32, easing: Easing) {
+		self.waypoints.push_back((frame, duration, easing));
+	}
+	/// Empties queue of waypoints, see [`Self::push_waypoint()`].
+	///
+	/// The segment currently animated is completed.
+	pub fn clear_waypoints(&mut self) {
+		self.waypoints.clear();
+	}
+	/// Cancels any animation in progress leaving the frame exactly where it currently is.
+	///
+	/// Clears standard view animations, waypoints, and pending smoothed zoom. Interactive
+	/// operations like pressing bound buttons, touching, or scrolling cancel animations
+	/// automatically so the user takes immediate control, except scrolling preserves pending
+	/// smoothed zoom.
+	pub fn cancel_animation(&mut self) {
+		self.interrupt();
+		self.zoom_pending = 0.0;
+	}
+	/// Cancels animations on user interaction.
+	fn interrupt(&mut self) {
+		self.animation = None;
+		self.waypoints.clear();
+		if let Some(inertia) = &mut self.orbit_inertia {
+			inertia.discard();
+		}
+		if let Some(inertia) = &mut self.pan_inertia {
+			inertia.discard();
+		}
+		if let Some(inertia) = &mut self.zoom_inertia {
+			inertia.discard();
+		}
+	}
+	/// Time constant of inertial motion, see [`Self::set_damping()`].
+	#[must_use]
+	pub fn damping(&self) -> f32 {
+		self.damping
+	}
+	/// Sets time constant of inertial motion in seconds.
+	///
+	/// Velocity carried on after releasing an operation decays exponentially as advanced by
+	/// [`Self::step()`], that is to about 37 % after `damping` seconds. Smaller values stop
+	/// sooner. Applies to all operations without their own time constant, see
+	/// [`Self::set_pan_damping()`] and [`Self::set_zoom_damping()`]. Enable inertia per
+	/// operation, e.g., via [`Self::set_orbit_inertia()`]. Default is `0.25`.
+	pub fn set_damping(&mut self, damping: f32) {
+		self.damping = damping;
+	}
+	/// Maximum velocity of inertial motion, see [`Self::set_max_inertia_velocity()`].
+	#[must_use]
+	pub fn max_inertia_velocity(&self) -> Option<f32> {
+		self.max_inertia_velocity
+	}
+	/// Sets maximum velocity of inertial motion captured at release.
+	///
+	/// Caps the velocity of all inertial operations uniformly, that is in radians per second for
+	/// orbit, in world units per second for pan, and in natural logarithm of distance ratio per
+	/// second for zoom, preventing runaway spins after fast flicks.
+	/// Use `None` to not cap velocity. Default is `None`.
+	pub fn set_max_inertia_velocity(&mut self, max: Option<f32>) {
+		self.max_inertia_velocity = max;
+	}
+	/// Whether orbit carries momentum, see [`Self::set_orbit_inertia()`].
+	#[must_use]
+	pub fn orbit_inertia(&self) -> bool {
+		self.orbit_inertia.is_some()
+	}
+	/// Sets whether orbit carries momentum after releasing it.
+	///
+	/// Keeps orbiting with the velocity of the last frame before release decaying wrt
+	/// [`Self::damping()`]. Default is `false`.
+	pub fn set_orbit_inertia(&mut self, orbit_inertia: bool) {
+		self.orbit_inertia = orbit_inertia.then(Inertia::default);
+	}
+	/// Whether pan carries momentum, see [`Self::set_pan_inertia()`].
+	#[must_use]
+	pub fn pan_inertia(&self) -> bool {
+		self.pan_inertia.is_some()
+	}
+	/// Sets whether pan carries momentum after releasing it.
+	///
+	/// Keeps sliding with the velocity of the last frame before releasing the slide button or
+	/// lifting a finger off a multi-finger slide decaying wrt [`Self::pan_damping()`]. Default is
+	/// `false`.
+	pub fn set_pan_inertia(&mut self, pan_inertia: bool) {
+		self.pan_inertia = pan_inertia.then(Inertia::default);
+	}
+	/// Own time constant in seconds of pan momentum, see [`Self::set_pan_damping()`].
+	#[must_use]
+	pub fn pan_damping(&self) -> Option<f32> {
+		self.pan_damping
+	}
+	/// Sets own time constant in seconds of pan momentum.
+	///
+	/// With `Some(damping)`, pan momentum decays wrt `damping` regardless of [`Self::damping()`],
+	/// e.g., for momentum orbit but precise panning. Use `None` to decay wrt
+	/// [`Self::damping()`]. Default is `None`.
+	pub fn set_pan_damping(&mut self, damping: Option<f32>) {
+		self.pan_damping = damping;
+	}
+	/// Whether zoom carries momentum, see [`Self::set_zoom_inertia()`].
+	#[must_use]
+	pub fn zoom_inertia(&self) -> bool {
+		self.zoom_inertia.is_some()
+	}
+	/// Sets whether zoom carries momentum after scrolling stopped.
+	///
+	/// Keeps zooming with the velocity of the last frame after scrolling stopped or after lifting
+	/// a finger off a pinch decaying wrt [`Self::zoom_damping()`]. The position under the cursor
+	/// or between the fingers stays fixed while decaying and the momentum stops as soon as the
+	/// target distance hits its bounds, see [`ClampKind::Distance`]. Default is `false`.
+	pub fn set_zoom_inertia(&mut self, zoom_inertia: bool) {
+		self.zoom_inertia = zoom_inertia.then(Inertia::default);
+	}
+	/// Own time constant in seconds of zoom momentum, see [`Self::set_zoom_damping()`].
+	#[must_use]
+	pub fn zoom_damping(&self) -> Option<f32> {
+		self.zoom_damping
+	}
+	/// Sets own time constant in seconds of zoom momentum.
+	///
+	/// With `Some(damping)`, zoom momentum decays wrt `damping` regardless of
+	/// [`Self::damping()`]. Use `None` to decay wrt [`Self::damping()`]. Default is `None`.
+	pub fn set_zoom_damping(&mut self, damping: Option<f32>) {
+		self.zoom_damping = damping;
+	}
+	/// Recenters target on axis-aligned box and sets distance to fit it with margin.
+	///
+	/// The box's bounding sphere enlarged by `margin_fraction` of its size fits the smaller of the
+	/// horizontal and vertical field of view for both perspective and orthographic projection.
+	/// Expressing the margin as fraction instead of in world units scales across model sizes.
+	/// Preserves rotation and applies boundary conditions.
+	pub fn zoom_to_fit(&mut self, min: Point3<f32>, max: Point3<f32>, margin_fraction: f32) {
+		let target = Point3::from((min.coords + max.coords) * 0.5);
+		let radius = (max - min).norm() * 0.5 * (1.0 + margin_fraction);
+		let half = self.fov_x().min(self.fov_y()) * 0.5;
+		let distance = if self.scene.ortho() {
+			radius / half.tan()
+		} else {
+			radius / half.sin()
+		};
+		self.set_clamped(from_parts(target, &rotation(&self.frame), distance));
+	}
+	/// Bounds of target position in world space, see [`Self::set_pan_bounds()`].
+	#[must_use]
+	pub fn pan_bounds(&self) -> Option<(Point3<f32>, Point3<f32>)> {
+		self.pan_bounds
+	}
+	/// Sets bounds of target position in world space as `(min, max)` corners of a box.
+	///
+	/// Whenever sliding would move the target outside the box, the slide is clamped per axis so
+	/// the target keeps sliding along the box's walls. Use `None` to disable bounds. Default is
+	/// `None`.
+	pub fn set_pan_bounds(&mut self, bounds: Option<(Point3<f32>, Point3<f32>)>) {
+		self.pan_bounds = bounds;
+		self.dirty = true;
+	}
+	/// Bounds of vertical half-extent in world units of orthographic projection, see
+	/// [`Self::set_ortho_extent_bounds()`].
+	#[must_use]
+	pub fn ortho_extent_bounds(&self) -> Option<(f32, f32)> {
+		self.ortho_extent_bounds
+	}
+	/// Sets bounds of vertical half-extent in world units of orthographic projection.
+	///
+	/// As the extent scales with the target distance, zooming is bounded by the visible extent
+	/// instead of the distance while [`Scene::ortho()`] is enabled, e.g., to keep grid lines
+	/// legible and to never zoom to zero extent. Use `None` for unbounded extent. Default is
+	/// `None`.
+	pub fn set_ortho_extent_bounds(&mut self, bounds: Option<(f32, f32)>) {
+		self.ortho_extent_bounds = bounds;
+		self.dirty = true;
+	}
+	/// Whether zoom bounds are consistent across projection modes, see
+	/// [`Self::set_link_projections()`].
+	#[must_use]
+	pub fn link_projections(&self) -> bool {
+		self.link_projections
+	}
+	/// Sets whether zoom bounds are consistent across projection modes.
+	///
+	/// Both projection modes derive the extent visible at the target from the target distance
+	/// and the field of view, so the apparent size of the target is kept when toggling via
+	/// [`Input::ortho_key()`]. While [`Self::clamp`] bounds the distance in both modes, the
+	/// [`Self::ortho_extent_bounds()`] only apply to orthographic projection unless linked. If
+	/// enabled, they bound the extent visible at the target in perspective projection as well, so
+	/// toggling never jumps back into bounds. Default is `false`.
+	pub fn set_link_projections(&mut self, link_projections: bool) {
+		self.link_projections = link_projections;
+		self.dirty = true;
+	}
+	/// Clamps target distance to keep orthographic half-extent within its bounds.
+	fn clamp_ortho_extent(&mut self) -> bool {
+		let Some((min, max)) = self
+			.ortho_extent_bounds
+			.filter(|_bounds| self.scene.ortho() || self.link_projections)
+		else {
+			return false;
+		};
+		let zat = self.frame.distance();
+		let half = self.scene.fov().max_and_upp(zat, self.image.max()).0.y;
+		let hit = half > 0.0 && !(min..=max).contains(&half);
+		if hit {
+			self.frame.set_distance(zat * half.clamp(min, max) / half);
+		}
+		hit
+	}
+	/// Slides eye and target back into pan bounds.
+	fn clamp_into_pan_bounds(&mut self) -> bool {
+		if let Some((min, max)) = self.pan_bounds {
+			let pos = *self.frame.target();
+			let vec = pos.coords.sup(&min.coords).inf(&max.coords) - pos.coords;
+			if vec != Vector3::zeros() {
+				self.frame.slide(&vec);
+				return true;
+			}
+		}
+		false
+	}
+	/// Clamps target distance to keep target beyond near clip plane.
+	fn clamp_beyond_near(&mut self) -> bool {
+		if !self.keep_beyond_near || self.scene.scale() {
+			return false;
+		}
+		let (znear, _zfar) = self.scene.clip_planes(self.frame.distance());
+		let min = znear * (1.0 + 1e-3);
+		let hit = self.frame.distance() < min;
+		if hit {
+			self.frame.set_distance(min);
+		}
+		hit
+	}
+	/// Clamps target distance to keep eye outside collision sphere.
+	fn clamp_out_of_sphere(&mut self) -> bool {
+		let Some((center, radius)) = self.collision_sphere else {
+			return false;
+		};
+		if (self.frame.eye() - center).norm() >= radius {
+			return false;
+		}
+		let vec = self.frame.target() - center;
+		let dir = self.frame.roll_axis();
+		let dot = vec.dot(&dir);
+		let distance = -dot
+			+ (dot * dot - vec.norm_squared() + radius * radius)
+				.max(0.0)
+				.sqrt();
+		self.frame.set_distance(distance);
+		true
+	}
+	/// Clamps elevation of eye around target to keep eye at or above ground plane.
+	fn clamp_above_ground(&mut self) -> bool {
+		if !self.keep_above_ground {
+			return false;
+		}
+		let vec = self.frame.eye() - self.frame.target();
+		let zat = vec.norm();
+		if zat <= 0.0 {
+			return false;
+		}
+		let min = (self.ground_height - self.frame.target().coords.dot(&self.up)) / zat;
+		let sin = vec.dot(&self.up) / zat;
+		let hit = sin < min;
+		if hit {
+			let axis = Unit::try_new(self.up.cross(&vec), f32::EPSILON)
+				.unwrap_or_else(|| self.frame.pitch_axis());
+			let angle = sin.asin() - min.min(1.0).asin();
+			self.frame
+				.orbit(&UnitQuaternion::from_axis_angle(&axis, angle));
+		}
+		hit
+	}
+	/// Orbit speed as multiplier of rotation angle per pixel, see [`Self::set_orbit_speed()`].
+	#[must_use]
+	pub fn orbit_speed(&self) -> f32 {
+		self.orbit_speed
+	}
+	/// Sets orbit speed as multiplier of rotation angle per pixel.
+	///
+	/// Scales the rotation computed by the orbit operation handler wrt mouse or finger movement.
+	/// Default is `1.0` which orbits one radian when dragging half of the screen's larger size.
+	pub fn set_orbit_speed(&mut self, speed: f32) {
+		self.orbit_speed = speed;
+	}
+	/// Sets orbit speed in degrees per drag across the full screen width.
+	///
+	/// Derives [`Self::set_orbit_speed()`] from the current screen size, so it must be invoked
+	/// again after the screen size changed to stay resolution-independent. Applies to
+	/// [`OrbitAlgorithm::Trackball`].
+	pub fn set_orbit_degrees_per_screen(&mut self, deg: f32) {
+		let max = self.image.max();
+		self.orbit_speed = deg.to_radians() * max.x.max(max.y) * 0.5 / max.x;
+	}
+	/// Whether orbit button orbits around cursor, see [`Self::set_orbit_around_cursor()`].
+	#[must_use]
+	pub fn orbit_around_cursor(&self) -> bool {
+		self.orbit_around_cursor
+	}
+	/// Sets whether orbit button orbits around cursor instead of target.
+	///
+	/// Pressing [`Input::orbit_button()`] captures the position under the cursor on the focus
+	/// plane as pivot to orbit around while dragging, e.g., to inspect off-center detail. Default
+	/// is `false`.
+	pub fn set_orbit_around_cursor(&mut self, orbit_around_cursor: bool) {
+		self.orbit_around_cursor = orbit_around_cursor;
+	}
+	/// Whether orbit speed scales with distance, see [`Self::set_orbit_distance_scaling()`].
+	#[must_use]
+	pub fn orbit_distance_scaling(&self) -> bool {
+		self.orbit_distance_scaling
+	}
+	/// Sets whether orbit speed scales with distance.
+	///
+	/// Multiplies [`Self::orbit_speed()`] by the ratio of current to [`Self::reset`] target
+	/// distance, so the same movement orbits farther when zoomed out and less when zoomed in.
+	/// Default is `false` orbiting at a constant rate regardless of distance.
+	pub fn set_orbit_distance_scaling(&mut self, orbit_distance_scaling: bool) {
+		self.orbit_distance_scaling = orbit_distance_scaling;
+	}
+	/// Whether two-finger gestures roll, see [`Self::set_touch_roll_enabled()`].
+	#[must_use]
+	pub fn touch_roll_enabled(&self) -> bool {
+		self.touch_roll_enabled
+	}
+	/// Sets whether two-finger gestures roll about the pinch center.
+	///
+	/// If disabled, two-finger gestures only slide and scale avoiding accidental roll while
+	/// sliding. Default is `true`.
+	pub fn set_touch_roll_enabled(&mut self, touch_roll_enabled: bool) {
+		self.touch_roll_enabled = touch_roll_enabled;
+	}
+	/// Whether one-finger gestures orbit, see [`Self::set_touch_orbit_enabled()`].
+	#[must_use]
+	pub fn touch_orbit_enabled(&self) -> bool {
+		self.touch_orbit_enabled
+	}
+	/// Sets whether one-finger gestures orbit around target.
+	///
+	/// If disabled, one-finger gestures only tap, e.g., for views of fixed orientation like the
+	/// orthographic views of [`MultiView`]. Default is `true`.
+	pub fn set_touch_orbit_enabled(&mut self, touch_orbit_enabled: bool) {
+		self.touch_orbit_enabled = touch_orbit_enabled;
+	}
+	/// Deadzone in pixels of multi-finger slide, see [`Self::set_touch_pan_threshold()`].
+	#[must_use]
+	pub fn touch_pan_threshold(&self) -> f32 {
+		self.touch_pan_threshold
+	}
+	/// Sets deadzone in pixels of multi-finger slide.
+	///
+	/// Multi-finger gestures only slide once the fingers' centroid moved farther than the
+	/// threshold since touching down. Together with [`Self::set_touch_pinch_threshold()`], only
+	/// the dominant gesture is applied until the other one exceeds its threshold as well,
+	/// reducing jitter of pure pinching while fingers drift slightly. Default is `0.0`.
+	pub fn set_touch_pan_threshold(&mut self, pixels: f32) {
+		self.touch_pan_threshold = pixels;
+	}
+	/// Deadzone as fraction of finger distance of two-finger pinch, see
+	/// [`Self::set_touch_pinch_threshold()`].
+	#[must_use]
+	pub fn touch_pinch_threshold(&self) -> f32 {
+		self.touch_pinch_threshold
+	}
+	/// Sets deadzone as fraction of finger distance of two-finger pinch.
+	///
+	/// Two-finger gestures only scale once the finger distance changed by more than the fraction,
+	/// e.g., `0.1` for 10 %, since touching down, reducing jitter of pure sliding while fingers
+	/// drift slightly in distance, see [`Self::set_touch_pan_threshold()`]. Default is `0.0`.
+	pub fn set_touch_pinch_threshold(&mut self, fraction: f32) {
+		self.touch_pinch_threshold = fraction;
+	}
+	/// Multiplier of orbit rotation angle wrt orbit speed and distance scaling.
+	fn orbit_factor(&self) -> f32 {
+		let zat = self.reset.distance();
+		if self.orbit_distance_scaling && zat > 0.0 {
+			self.orbit_speed * self.frame.distance() / zat
+		} else {
+			self.orbit_speed
+		}
+	}
+	/// Slide speed as multiplier of slide vector, see [`Self::set_slide_speed()`].
+	#[must_use]
+	pub fn slide_speed(&self) -> f32 {
+		self.slide_speed
+	}
+	/// Sets slide speed as multiplier of slide vector.
+	///
+	/// Scales the mouse or finger movement projected onto the focus plane. Default is `1.0` which
+	/// keeps the focus plane under the cursor while sliding.
+	pub fn set_slide_speed(&mut self, speed: f32) {
+		self.slide_speed = speed;
+	}
+	/// Pan speed in world units per second of keyboard pan, see
+	/// [`Self::set_keyboard_pan_speed()`].
+	#[must_use]
+	pub fn keyboard_pan_speed(&self) -> f32 {
+		self.keyboard_pan_speed
+	}
+	/// Sets pan speed in world units per second of keyboard pan.
+	///
+	/// Slides eye and target continuously on the focus plane as advanced by [`Self::step()`] as
+	/// long as any [`Input::pan_key()`] is pressed, moving the view in its direction, e.g., for
+	/// keyboard-only map navigation. Default is `1.0`.
+	pub fn set_keyboard_pan_speed(&mut self, speed: f32) {
+		self.keyboard_pan_speed = speed;
+	}
+	/// Cursor movement in pixels before the slide button slides, see
+	/// [`Self::set_slide_threshold()`].
+	#[must_use]
+	pub fn slide_threshold(&self) -> f32 {
+		self.slide_threshold
+	}
+	/// Sets cursor movement in pixels before the slide button slides.
+	///
+	/// Sliding only engages once the cursor moved farther than the threshold from where the slide
+	/// button has been pressed. A press-release without engaging does not slide and its release
+	/// event is not consumed by [`Self::handle_event_consumed()`], letting applications treat it
+	/// as a click, e.g., to open a context menu. Default is `0.0`.
+	pub fn set_slide_threshold(&mut self, pixels: f32) {
+		self.slide_threshold = pixels;
+	}
+	/// Cursor movement in pixels before the orbit button orbits, see
+	/// [`Self::set_drag_threshold()`].
+	#[must_use]
+	pub fn drag_threshold(&self) -> f32 {
+		self.drag_threshold
+	}
+	/// Sets cursor movement in pixels before the orbit button orbits.
+	///
+	/// Orbiting only engages once the cursor moved farther than the threshold from where the orbit
+	/// button has been pressed, starting from where it engaged. A press-release without engaging
+	/// is a click even if the cursor has been shaken slightly, see
+	/// [`Self::set_focus_on_release()`]. Default is `0.0`.
+	pub fn set_drag_threshold(&mut self, pixels: f32) {
+		self.drag_threshold = pixels;
+	}
+	/// Jitter radius in pixels smoothed out of cursor positions, see
+	/// [`Self::set_input_smoothing()`].
+	#[must_use]
+	pub fn input_smoothing(&self) -> Option<f32> {
+		self.input_smoothing
+	}
+	/// Sets jitter radius in pixels smoothed out of cursor positions while operating.
+	///
+	/// Filters cursor positions before they reach the orbit, slide, and scale operations, e.g., of
+	/// jittery trackpads or pen tablets. The filter adapts to velocity, smoothing out slow
+	/// movements within the radius while passing fast ones through without perceptible lag. Use
+	/// `None` to disable. Default is `None`.
+	pub fn set_input_smoothing(&mut self, radius: Option<f32>) {
+		self.input_smoothing = radius;
+		self.smoothing.discard();
+	}
+	/// Orbits eye around target by yaw and pitch angles in radians.
+	///
+	/// Rotates about [`Frame::local_yaw_axis()`] and [`Frame::local_pitch_axis()`] in the sense
+	/// prescribed by the right-hand rule bypassing the screen-space orbit operation handler, e.g.,
+	/// for gamepad or scripted control.
+	pub fn orbit_delta(&mut self, yaw: f32, pitch: f32) {
+		let yaw = UnitQuaternion::from_axis_angle(&self.frame.local_yaw_axis(), yaw);
+		let pitch = UnitQuaternion::from_axis_angle(&self.frame.local_pitch_axis(), pitch);
+		self.frame.local_orbit(&(yaw * pitch));
+	}
+	/// Rolls eye about view direction by angle in radians.
+	///
+	/// Rotates about [`Frame::local_roll_axis()`] in the sense prescribed by the right-hand rule
+	/// like the two-finger touch roll does.
+	pub fn roll(&mut self, angle: f32) {
+		let rot = UnitQuaternion::from_axis_angle(&self.frame.local_roll_axis(), angle);
+		self.frame.local_orbit(&rot);
+	}
+	/// Roll angle in radians of eye about view direction wrt [`Self::up_axis()`].
+	///
+	/// Measures the angle between the camera's up vector and the plane containing world-up axis
+	/// and view direction in the sense of [`Self::roll()`], e.g., for a horizon indicator. Is zero
+	/// if the horizon is level or the view direction is parallel to the world-up axis.
+	#[must_use]
+	pub fn roll_angle(&self) -> f32 {
+		let z = self.frame.roll_axis();
+		let Some(y) = Unit::try_new(
+			self.up.into_inner() - z.into_inner() * self.up.dot(&z),
+			1e-6,
+		) else {
+			return 0.0;
+		};
+		let x = y.cross(&z);
+		let up = self.frame.yaw_axis();
+		(-up.dot(&x)).atan2(up.dot(&y))
+	}
+	/// Sets absolute roll angle in radians of eye about view direction wrt [`Self::up_axis()`].
+	///
+	/// Rolls by the difference to [`Self::roll_angle()`], e.g., `set_roll(0.0)` levels the
+	/// horizon.
+	pub fn set_roll(&mut self, angle: f32) {
+		self.roll(angle - self.roll_angle());
+	}
+	/// Orbit speed in radians per second of keyboard orbit, see
+	/// [`Self::set_keyboard_orbit_step()`].
+	#[must_use]
+	pub fn keyboard_orbit_step(&self) -> f32 {
+		self.keyboard_orbit_step
+	}
+	/// Sets orbit speed in radians per second of keyboard orbit.
+	///
+	/// Orbits continuously as advanced by [`Self::step()`] as long as any [`Input::orbit_key()`]
+	/// is pressed in the sense of dragging the orbit button in its direction, independent of the
+	/// key repeat of the operating system. Default is `π/2`.
+	pub fn set_keyboard_orbit_step(&mut self, step: f32) {
+		self.keyboard_orbit_step = step;
+	}
+	/// Lets target lag-follow moving point, see [`Self::set_follow_reaim()`].
+	///
+	/// Moves target towards `target` by blend factor `1 - exp(-dt / smoothing)` where `smoothing`
+	/// is the time constant and `dt` the frame time, both in seconds. A `smoothing` of zero snaps
+	/// onto `target`. Call once per frame.
+	pub fn follow_target(&mut self, target: Point3<f32>, smoothing: f32, dt: f32) {
+		let rat = blend(smoothing, dt);
+		let old = *self.frame.target();
+		let new = old + (target - old) * rat;
+		if self.follow_reaim {
+			self.frame = Frame::look_at(new, &self.frame.eye(), &self.frame.yaw_axis());
+		} else {
+			self.frame.slide(&(new - old));
+		}
+	}
+	/// Whether eye stays and re-aims at followed target, see [`Self::follow_target()`].
+	#[must_use]
+	pub fn follow_reaim(&self) -> bool {
+		self.follow_reaim
+	}
+	/// Sets whether eye stays and re-aims at followed target, see [`Self::follow_target()`].
+	///
+	/// Default is `false` which moves the eye rigidly with the target preserving their offset.
+	pub fn set_follow_reaim(&mut self, reaim: bool) {
+		self.follow_reaim = reaim;
+	}
+	/// Whether scrolling zooms logarithmically, see [`Self::set_log_zoom()`].
+	#[must_use]
+	pub fn log_zoom(&self) -> bool {
+		self.log_zoom
+	}
+	/// Sets whether scrolling zooms logarithmically.
+	///
+	/// Scales distance by the fixed ratio `exp(-delta / denominator)` per scroll delta instead of
+	/// `1 - delta / denominator` resulting in constant perceptual zoom speed across scales and
+	/// symmetric zooming in and out. Default is `false`.
+	pub fn set_log_zoom(&mut self, log_zoom: bool) {
+		self.log_zoom = log_zoom;
+	}
+	/// Effective vertical field of view in radians wrt current screen size.
+	///
+	/// Regardless of which quantity [`Scene::fov()`] fixes, see [`Fixed`].
+	#[must_use]
+	pub fn fov_y(&self) -> f32 {
+		self.scene.fov().to_ver(self.image.max()).into_inner()
+	}
+	/// Effective horizontal field of view in radians wrt current screen size.
+	///
+	/// Regardless of which quantity [`Scene::fov()`] fixes, see [`Fixed`].
+	#[must_use]
+	pub fn fov_x(&self) -> f32 {
+		self.scene.fov().to_hor(self.image.max()).into_inner()
+	}
+	/// Viewport origin and size in pixels of window, see [`Self::set_viewport()`].
+	#[must_use]
+	pub fn viewport(&self) -> (Point2<f32>, Point2<f32>) {
+		(self.origin(), *self.image.max())
+	}
+	/// Sets viewport origin and size in pixels of window, e.g., for split screen.
+	///
+	/// Maps cursor and finger positions relative to the viewport's origin at its top left corner
+	/// and computes the projection for its size instead of the framebuffer's one which is ignored
+	/// from now on. The application is responsible for rendering into the viewport's rectangle.
+	/// Default is the whole framebuffer, see [`Self::reset_viewport()`].
+	pub fn set_viewport(&mut self, origin: Point2<f32>, size: Point2<f32>) {
+		self.viewport = Some(origin);
+		self.image.set_max(size);
+	}
+	/// Resets viewport to whole framebuffer, see [`Self::set_viewport()`].
+	///
+	/// Its size is updated on the next framebuffer size change.
+	pub fn reset_viewport(&mut self) {
+		self.viewport = None;
+	}
+	/// Viewport origin in pixels of window.
+	fn origin(&self) -> Point2<f32> {
+		self.viewport.unwrap_or_else(Point2::origin)
+	}
+	/// Aspect ratio overriding the one of the framebuffer, see [`Self::set_aspect_override()`].
+	#[must_use]
+	pub fn aspect_override(&self) -> Option<f32> {
+		self.aspect_override
+	}
+	/// Sets aspect ratio of width to height overriding the one of the framebuffer.
+	///
+	/// Computes the projection as if the framebuffer had the given aspect ratio at its current
+	/// height, e.g., for rendering to an offscreen target of fixed 16:9 regardless of window size.
+	/// Interactions still refer to the framebuffer. Use `None` to restore the aspect ratio of the
+	/// framebuffer. Default is `None`.
+	pub fn set_aspect_override(&mut self, aspect: Option<f32>) {
+		self.aspect_override = aspect;
+		self.dirty = true;
+	}
+	/// Whether to letterbox instead of stretch, see [`Self::set_letterbox()`].
+	#[must_use]
+	pub fn letterbox(&self) -> bool {
+		self.letterbox
+	}
+	/// Sets whether to letterbox instead of stretch wrt [`Self::aspect_override()`].
+	///
+	/// If the overridden aspect ratio differs from the one of the framebuffer, the projection is
+	/// shrunk horizontally (pillarbox) or vertically (letterbox) to keep the content undistorted
+	/// and centered. The camera only guarantees undistorted content, the application is
+	/// responsible for clearing the bar regions. Default is `false`.
+	pub fn set_letterbox(&mut self, letterbox: bool) {
+		self.letterbox = letterbox;
+		self.dirty = true;
+	}
+	/// Lower left, lower right, and upper left corners of projection screen in camera space, see
+	/// [`Self::set_projection_screen()`].
+	#[must_use]
+	pub fn projection_screen(&self) -> Option<[Point3<f32>; 3]> {
+		self.projection_screen
+	}
+	/// Sets generalized off-axis perspective projection onto physical screen.
+	///
+	/// The screen is defined by its lower left, lower right, and upper left corners in camera
+	/// space, that is relative to the eye with the camera's axes, e.g., as measured for tiles of
+	/// display walls or CAVE walls. The projection follows Kooima's generalized perspective
+	/// projection with the clip planes of [`Self::scene`] and takes precedence over
+	/// [`Scene::ortho()`] and [`Self::aspect_override()`].
+	pub fn set_projection_screen(
+		&mut self,
+		lower_left: Point3<f32>,
+		lower_right: Point3<f32>,
+		upper_left: Point3<f32>,
+	) {
+		self.projection_screen = Some([lower_left, lower_right, upper_left]);
+		self.dirty = true;
+	}
+	/// Clears projection screen restoring projection of [`Self::scene`].
+	pub fn clear_projection_screen(&mut self) {
+		self.projection_screen = None;
+		self.dirty = true;
+	}
+	/// Generalized off-axis perspective projection onto screen wrt clip planes.
+	fn screen_projection(&self, [pa, pb, pc]: [Point3<f32>; 3]) -> Matrix4<f32> {
+		let vr = (pb - pa).normalize();
+		let vu = (pc - pa).normalize();
+		let vn = vr.cross(&vu).normalize();
+		let (znear, zfar) = self.scene.clip_planes(self.frame.distance());
+		let scale = znear / -pa.coords.dot(&vn);
+		let (left, right) = (vr.dot(&pa.coords) * scale, vr.dot(&pb.coords) * scale);
+		let (bottom, top) = (vu.dot(&pa.coords) * scale, vu.dot(&pc.coords) * scale);
+		let (depth, shift) = if self.infinite_far {
+			(-1.0, -2.0 * znear)
+		} else {
+			(
+				-(zfar + znear) / (zfar - znear),
+				-2.0 * zfar * znear / (zfar - znear),
+			)
+		};
+		let (width, height) = (right - left, top - bottom);
+		#[rustfmt::skip]
+		let frustum = Matrix4::new(
+			2.0 * znear / width, 0.0, (right + left) / width, 0.0,
+			0.0, 2.0 * znear / height, (top + bottom) / height, 0.0,
+			0.0, 0.0, depth, shift,
+			0.0, 0.0, -1.0, 0.0,
+		);
+		#[rustfmt::skip]
+		let rotation = Matrix4::new(
+			vr.x, vr.y, vr.z, 0.0,
+			vu.x, vu.y, vu.z, 0.0,
+			vn.x, vn.y, vn.z, 0.0,
+			0.0, 0.0, 0.0, 1.0,
+		);
+		frustum * rotation
+	}
+	/// Whether projection maps depth reversely, see [`Self::set_reversed_z()`].
+	#[must_use]
+	pub fn reversed_z(&self) -> bool {
+		self.reversed_z
+	}
+	/// Sets whether projection maps depth reversely for improved depth precision.
+	///
+	/// Maps near clip plane to normalized device depth of `1` and far clip plane to `0` instead of
+	/// `-1` and `1`. The application must configure its depth test accordingly, i.e., `GL_GREATER`
+	/// instead of `GL_LESS` and clearing the depth buffer with `0` instead of `1`. The precision
+	/// gain requires a clip depth range of `[0, 1]`, i.e., `glClipControl()` with `GL_ZERO_TO_ONE`,
+	/// as a depth test of `GL_GREATER` alone only uses half of the default range of `[-1, 1]`.
+	/// Default is `false`.
+	pub fn set_reversed_z(&mut self, reversed_z: bool) {
+		self.reversed_z = reversed_z;
+		self.dirty = true;
+	}
+	/// Whether perspective projection has an infinite far clip plane, see
+	/// [`Self::set_infinite_far()`].
+	#[must_use]
+	pub fn infinite_far(&self) -> bool {
+		self.infinite_far
+	}
+	/// Sets whether perspective projection has an infinite far clip plane.
+	///
+	/// Avoids far clipping, e.g., for skyboxes or very large terrains. Then
+	/// [`Camera::clip_planes()`] reports a far clip plane distance of [`f32::INFINITY`].
+	/// Orthographic projection is not affected. Default is `false`.
+	pub fn set_infinite_far(&mut self, infinite_far: bool) {
+		self.infinite_far = infinite_far;
+		self.dirty = true;
+	}
+	/// Auto-rotation speed in radians per second, see [`Self::set_auto_rotate()`].
+	#[must_use]
+	pub fn auto_rotate(&self) -> Option<f32> {
+		self.auto_rotate
+	}
+	/// Sets auto-rotation speed in radians per second.
+	///
+	/// With `Some(speed)`, [`Self::step()`] continuously orbits the eye around the target about
+	/// [`Self::up_axis()`], e.g., for a product showcase. Any consumed user input pauses it, see
+	/// [`Self::set_auto_rotate_timeout()`]. Default is `None`.
+	pub fn set_auto_rotate(&mut self, speed: Option<f32>) {
+		self.auto_rotate = speed;
+		self.auto_rotate_toggle = None;
+		self.auto_rotate_paused = false;
+	}
+	/// Toggles auto-rotation off and on again restoring its previous speed.
+	///
+	/// Does nothing if auto-rotation has never been set, see [`Self::set_auto_rotate()`].
+	pub fn toggle_auto_rotate(&mut self) {
+		core::mem::swap(&mut self.auto_rotate, &mut self.auto_rotate_toggle);
+		self.auto_rotate_paused = false;
+	}
+	/// Idle timeout in seconds before auto-rotation begins, see
+	/// [`Self::set_auto_rotate_timeout()`].
+	#[must_use]
+	pub fn auto_rotate_timeout(&self) -> Option<f32> {
+		self.auto_rotate_timeout
+	}
+	/// Sets idle timeout in seconds before auto-rotation begins.
+	///
+	/// With `Some(timeout)`, auto-rotation begins only once [`Self::idle_time()`] exceeds the
+	/// timeout, initially as well as after user input. Use `None` to auto-rotate right away but
+	/// to keep auto-rotation paused after consumed user input. Default is `None`.
+	pub fn set_auto_rotate_timeout(&mut self, timeout: Option<f32>) {
+		self.auto_rotate_timeout = timeout;
+	}
+	/// Time in seconds since the last mouse, touch, key, scroll, or gamepad input as accumulated
+	/// by [`Self::step()`].
+	#[must_use]
+	pub fn idle_time(&self) -> f32 {
+		self.idle
+	}
+	/// Advances time-dependent camera motion by frame time `dt` in seconds.
+	///
+	/// Call once per frame before rendering, e.g., for [`Self::set_auto_rotate()`]. Otherwise,
+	/// [`Camera::update()`] advances by [`Self::frame_time()`]. See [`Self::update_dt()`].
+	pub fn step(&mut self, dt: f32) {
+		self.stepped = true;
+		self.view_changed = false;
+		self.clock += dt;
+		if self.animation.is_none() {
+			if let Some((frame, duration, easing)) = self.waypoints.pop_front() {
+				self.animation = Some(Animation::new(self.frame.clone(), frame, duration, easing));
+			}
+		}
+		if let Some(animation) = &mut self.animation {
+			let (frame, finished) = animation.compute(dt);
+			self.frame = frame;
+			if finished {
+				self.animation = None;
+			}
+		}
+		self.idle += dt;
+		let active = self
+			.auto_rotate_timeout
+			.map_or(!self.auto_rotate_paused, |timeout| self.idle >= timeout);
+		if let Some(speed) = self.auto_rotate.filter(|_speed| active) {
+			self.frame
+				.orbit(&UnitQuaternion::from_axis_angle(&self.up, speed * dt));
+		}
+		if let Some(smoothing) = self.zoom_smoothing {
+			if self.zoom_pending != 0.0 {
+				let log = if self.zoom_pending.abs() < 1e-4 {
+					self.zoom_pending
+				} else {
+					self.zoom_pending * blend(smoothing, dt)
+				};
+				self.zoom_pending -= log;
+				self.frame
+					.local_scale_around(log.exp(), &self.project_pos(self.zoom_anchor));
+			}
+		}
+		if let Some(inertia) = &mut self.orbit_inertia {
+			inertia.sample(dt);
+			if let Some(vec) = inertia.compute(dt, self.damping) {
+				self.frame
+					.local_orbit(&UnitQuaternion::from_scaled_axis(vec));
+			}
+		}
+		let damping = self.zoom_damping.unwrap_or(self.damping);
+		if let Some(inertia) = &mut self.zoom_inertia {
+			let scrolled = core::mem::take(&mut self.zoom_scrolled);
+			if core::mem::replace(&mut self.zoom_scrolling, scrolled) && !scrolled {
+				inertia.release(self.max_inertia_velocity);
+			}
+			inertia.sample(dt);
+			if let Some(vec) = inertia.compute(dt, damping) {
+				self.frame
+					.local_scale_around(vec.x.exp(), &self.project_pos(self.zoom_anchor));
+			}
+		}
+		let damping = self.pan_damping.unwrap_or(self.damping);
+		if let Some(inertia) = &mut self.pan_inertia {
+			inertia.sample(dt);
+			if let Some(vec) = inertia.compute(dt, damping) {
+				self.frame.local_slide(&vec);
+			}
+		}
+		let vec = Direction::sum(self.orbit_keys) * self.keyboard_orbit_step * dt;
+		if vec != Vector2::zeros() {
+			self.orbit_delta(-vec.x, vec.y);
+		}
+		let [zoom_in, zoom_out] = self.zoom_keys;
+		if zoom_in != zoom_out {
+			let sign = if zoom_in { 1.0 } else { -1.0 };
+			self.apply_scroll(sign * self.keyboard_zoom_speed * dt);
+		}
+		let vec = Direction::sum(self.pan_keys) * self.keyboard_pan_speed * dt;
+		if vec != Vector2::zeros() {
+			self.frame.local_slide(&Vector3::new(vec.x, vec.y, 0.0));
+		}
+		if self.first.enabled() {
+			let [up, down] = self
+				.first_vertical
+				.map(|pressed| if pressed { 1.0 } else { 0.0 });
+			self.frame
+				.slide(&(self.up.into_inner() * (up - down) * self.first_speed * dt));
+		}
+		if let Some(fly) = &mut self.fly {
+			let vec = fly.compute(
+				dt,
+				self.fly_max_speed,
+				self.fly_acceleration,
+				self.fly_sprint,
+			);
+			let mut vec = rotation(&self.frame) * vec;
+			if self.walk_height.is_some() {
+				let len = vec.norm();
+				vec -= self.up.into_inner() * vec.dot(&self.up);
+				vec = vec.try_normalize(f32::EPSILON).unwrap_or_default() * len;
+			}
+			self.frame.slide(&vec);
+		}
+		if let Some(height) = self.walk_height.filter(|_height| self.first.enabled()) {
+			let lift = self.ground_height + height - self.frame.eye().coords.dot(&self.up);
+			self.frame.slide(&(self.up.into_inner() * lift));
+		}
+	}
+	/// Whether free-fly camera mode is enabled, see [`Self::set_fly_mode()`].
+	#[must_use]
+	pub fn fly_mode(&self) -> bool {
+		self.fly.is_some()
+	}
+	/// Sets whether free-fly camera mode is enabled.
+	///
+	/// Unlike first person view as long as [`Input::first_key()`] is pressed, this mode persists
+	/// and moves eye and target through space via [`Input::fly_move_key()`] with continuous
+	/// mouselook. The target is kept at its distance in front of the eye, so returning to
+	/// trackball mode is seamless. Usually toggled via [`Input::fly_key()`], as leaving it
+	/// programmatically does not release a grabbed cursor. Default is `false`.
+	pub fn set_fly_mode(&mut self, fly: bool) {
+		if fly == self.fly.is_some() {
+			return;
+		}
+		self.slide.discard();
+		if fly {
+			self.fly = Some(FlyMode::default());
+			if !self.first.enabled() {
+				self.first.capture(self.up);
+			}
+			self.image.set_pos(self.image.max() * 0.5);
+		} else {
+			self.fly = None;
+			self.first.discard();
+		}
+	}
+	/// Whether first person view is enabled, see [`Self::set_first_person()`].
+	#[must_use]
+	pub fn first_person(&self) -> bool {
+		self.first.enabled()
+	}
+	/// Sets whether first person view is enabled.
+	///
+	/// Enables or disables first person view like pressing or releasing [`Input::first_key()`],
+	/// e.g., to bind it to a button of the user interface or a gamepad. The cursor is grabbed and
+	/// hidden on the next cursor movement and released on the next handled event.
+	pub fn set_first_person(&mut self, first_person: bool) {
+		if first_person {
+			if !self.first.enabled() {
+				self.first.capture(self.up);
+				self.image.set_pos(self.image.max() * 0.5);
+			}
+		} else if self.first.enabled() {
+			self.slide.discard();
+			self.first.discard();
+			self.release_pending = true;
+		}
+	}
+	/// Vertical speed of first person view, see [`Self::set_first_speed()`].
+	#[must_use]
+	pub fn first_speed(&self) -> f32 {
+		self.first_speed
+	}
+	/// Sets vertical speed of first person view in units per second.
+	///
+	/// Moves along the world-up axis as long as [`Input::first_up_key()`] or
+	/// [`Input::first_down_key()`] is pressed as advanced by [`Self::step()`]. Default is `1.0`.
+	pub fn set_first_speed(&mut self, speed: f32) {
+		self.first_speed = speed;
+	}
+	/// Eye height above ground plane in first person view, see [`Self::set_walk_height()`].
+	#[must_use]
+	pub fn walk_height(&self) -> Option<f32> {
+		self.walk_height
+	}
+	/// Sets eye height above ground plane in first person view.
+	///
+	/// Pins the eye at the given height above [`Self::ground_height()`] along the world-up axis
+	/// regardless of pitch as advanced by [`Self::step()`], e.g., for walkthroughs at human eye
+	/// height. Movement keys then only move horizontally. Use `None` to fly freely. Default is
+	/// `None`.
+	pub fn set_walk_height(&mut self, height: Option<f32>) {
+		self.walk_height = height;
+	}
+	/// Maximum speed of free-fly camera mode, see [`Self::set_fly_max_speed()`].
+	#[must_use]
+	pub fn fly_max_speed(&self) -> f32 {
+		self.fly_max_speed
+	}
+	/// Sets maximum speed of free-fly camera mode in units per second.
+	///
+	/// Moves are advanced in [`Self::step()`]. Default is `1.0`.
+	pub fn set_fly_max_speed(&mut self, speed: f32) {
+		self.fly_max_speed = speed;
+	}
+	/// Acceleration of free-fly camera mode, see [`Self::set_fly_acceleration()`].
+	#[must_use]
+	pub fn fly_acceleration(&self) -> Option<f32> {
+		self.fly_acceleration
+	}
+	/// Sets acceleration of free-fly camera mode in units per second squared.
+	///
+	/// Holding movement keys ramps velocity up to [`Self::fly_max_speed()`] and releasing them
+	/// decelerates at the same rate. Use `None` to move at full speed instantly. Default is
+	/// `None`.
+	pub fn set_fly_acceleration(&mut self, acceleration: Option<f32>) {
+		self.fly_acceleration = acceleration;
+	}
+	/// Sprint multiplier of free-fly camera mode, see [`Self::set_fly_sprint()`].
+	#[must_use]
+	pub fn fly_sprint(&self) -> f32 {
+		self.fly_sprint
+	}
+	/// Sets sprint multiplier of free-fly camera mode.
+	///
+	/// Multiplies [`Self::fly_max_speed()`] as long as [`Input::fly_sprint_key()`] is pressed.
+	/// Default is `2.0`.
+	pub fn set_fly_sprint(&mut self, sprint: f32) {
+		self.fly_sprint = sprint;
+	}
+	/// Action of two-finger tap, see [`Self::set_two_finger_tap()`].
+	#[must_use]
+	pub fn two_finger_tap(&self) -> Option<fn(&mut Trackball)> {
+		self.two_finger_tap
+	}
+	/// Sets action of two-finger tap.
+	///
+	/// A two-finger tap is recognized when both fingers touch down and up within
+	/// [`Self::tap_window()`] without moving farther than [`Self::tap_tolerance()`]. It replaces
+	/// sliding to the fingers' position. Use `None` to disable gesture. Default is
+	/// [`Self::reset_frame()`].
+	pub fn set_two_finger_tap(&mut self, action: Option<fn(&mut Trackball)>) {
+		self.two_finger_tap = action;
+	}
+	/// Time window in seconds of two-finger tap, see [`Self::set_tap_window()`].
+	#[must_use]
+	pub fn tap_window(&self) -> f32 {
+		self.tap_window
+	}
+	/// Sets time window in seconds of two-finger tap.
+	///
+	/// Time is measured as accumulated by [`Self::step()`]. Default is `0.3`.
+	pub fn set_tap_window(&mut self, window: f32) {
+		self.tap_window = window;
+	}
+	/// Movement tolerance in pixels of two-finger tap, see [`Self::set_tap_tolerance()`].
+	#[must_use]
+	pub fn tap_tolerance(&self) -> f32 {
+		self.tap_tolerance
+	}
+	/// Sets movement tolerance in pixels of two-finger tap.
+	///
+	/// Default is `10.0`.
+	pub fn set_tap_tolerance(&mut self, tolerance: f32) {
+		self.tap_tolerance = tolerance;
+	}
+	/// Like [`Camera::update()`] but advances time-dependent camera motion by frame time `dt` in
+	/// seconds.
+	///
+	/// Applications with inertia, smoothing, or animations should call this once per frame with
+	/// their measured frame time for framerate-independent motion. Then the subsequent
+	/// [`Camera::update()`] invoked by [`kiss3d`] while rendering does not advance again.
+	pub fn update_dt(&mut self, dt: f32) {
+		self.step(dt);
+		self.compute();
+	}
+	/// Default frame time in seconds, see [`Self::set_frame_time()`].
+	#[must_use]
+	pub fn frame_time(&self) -> f32 {
+		self.frame_time
+	}
+	/// Sets default frame time in seconds.
+	///
+	/// Used by [`Camera::update()`] to advance time-dependent camera motion unless advanced
+	/// since the previous update via [`Self::step()`] or [`Self::update_dt()`]. Default is
+	/// `1.0 / 60.0`.
+	pub fn set_frame_time(&mut self, dt: f32) {
+		self.frame_time = dt;
+	}
+	/// Time constant in seconds of smooth scroll zoom, see [`Self::set_zoom_smoothing()`].
+	#[must_use]
+	pub fn zoom_smoothing(&self) -> Option<f32> {
+		self.zoom_smoothing
+	}
+	/// Sets time constant in seconds of smooth scroll zoom.
+	///
+	/// With `Some(smoothing)`, scrolling accumulates the zoom which [`Self::step()`] eases out
+	/// by blend factor `1 - exp(-dt / smoothing)` per frame. As the remaining zoom decays by
+	/// `exp(-t / smoothing)` after time `t` regardless of how it is divided into frames, zooming
+	/// feels identical at any framerate given measured frame times, see [`Self::update_dt()`].
+	/// The remainder is applied at once when negligible. The world point under the cursor
+	/// when scrolling stays under it throughout the interpolation as the pivot on the focus plane
+	/// is recomputed each step. Use `None` to zoom instantaneously. Default is `None`.
+	pub fn set_zoom_smoothing(&mut self, smoothing: Option<f32>) {
+		self.zoom_smoothing = smoothing;
+		if smoothing.is_none() {
+			self.zoom_pending = 0.0;
+		}
+	}
+	/// Zoom speed in scroll units per second of keyboard zoom, see
+	/// [`Self::set_keyboard_zoom_speed()`].
+	#[must_use]
+	pub fn keyboard_zoom_speed(&self) -> f32 {
+		self.keyboard_zoom_speed
+	}
+	/// Sets zoom speed in scroll units per second of keyboard zoom.
+	///
+	/// Zooms continuously like scrolling as advanced by [`Self::step()`] as long as
+	/// [`Input::zoom_in_key()`] or [`Input::zoom_out_key()`] is pressed, see
+	/// [`Self::apply_scroll()`]. Default is `120.0`, one scroll unit of [`Scale::denominator()`]
+	/// per second.
+	pub fn set_keyboard_zoom_speed(&mut self, speed: f32) {
+		self.keyboard_zoom_speed = speed;
+	}
+	/// Whether scrolling slides instead of scales, see [`Self::set_touchpad_scroll_pans()`].
+	#[must_use]
+	pub fn touchpad_scroll_pans(&self) -> bool {
+		self.touchpad_scroll_pans
+	}
+	/// Sets whether scrolling slides instead of scales.
+	///
+	/// Matches the convention of precision touchpads reporting two-finger scroll as scroll events
+	/// by sliding the trackball on the focus plane moving the scene along with the fingers. Keep
+	/// it disabled for mouse wheels. Default is `false`.
+	pub fn set_touchpad_scroll_pans(&mut self, touchpad_scroll_pans: bool) {
+		self.touchpad_scroll_pans = touchpad_scroll_pans;
+	}
+	/// Whether releasing without dragging slides to cursor or finger position, see
+	/// [`Self::set_focus_on_release()`].
+	#[must_use]
+	pub fn focus_on_release(&self) -> bool {
+		self.focus_on_release
+	}
+	/// Sets whether releasing without dragging slides to cursor or finger position.
+	///
+	/// Slides the target to the position where [`Input::orbit_button()`] or any finger was
+	/// pressed and released without dragging, see [`Self::set_double_click_window()`] to require
+	/// double-clicks instead and [`Input::set_focus_modifiers()`] to require modifiers. Default is
+	/// `true`.
+	pub fn set_focus_on_release(&mut self, focus_on_release: bool) {
+		self.focus_on_release = focus_on_release;
+	}
+	/// Time window in seconds of double-click focus, see [`Self::set_double_click_window()`].
+	#[must_use]
+	pub fn double_click_window(&self) -> Option<f32> {
+		self.double_click_window
+	}
+	/// Sets time window in seconds of double-click focus.
+	///
+	/// With `Some(window)`, [`Self::focus_on_release()`] requires a second release within window
+	/// and within [`Self::double_click_tolerance()`] of the first one, both without dragging.
+	/// Time is measured as accumulated by [`Self::step()`]. Use `None` to focus on every release.
+	/// Default is `None`.
+	pub fn set_double_click_window(&mut self, window: Option<f32>) {
+		self.double_click_window = window;
+		self.last_click = None;
+	}
+	/// Movement tolerance in pixels of double-click focus, see
+	/// [`Self::set_double_click_tolerance()`].
+	#[must_use]
+	pub fn double_click_tolerance(&self) -> f32 {
+		self.double_click_tolerance
+	}
+	/// Sets movement tolerance in pixels of double-click focus.
+	///
+	/// Maximum distance between the positions of both releases, use `0.0` to reject any movement
+	/// between them. Default is `5.0`.
+	pub fn set_double_click_tolerance(&mut self, tolerance: f32) {
+		self.double_click_tolerance = tolerance;
+	}
+	/// Slides target to position of release without dragging wrt double-click focus and focus
+	/// modifiers.
+	fn focus_on_click(&mut self, pos: Point2<f32>, modifiers: Modifiers) {
+		if !self.focus_on_release || self.input.focus_modifiers().is_some_and(|m| m != modifiers) {
+			return;
+		}
+		if let Some(window) = self.double_click_window {
+			let double = self.last_click.take().is_some_and(|(old, time)| {
+				self.clock - time <= window && (pos - old).norm() <= self.double_click_tolerance
+			});
+			if !double {
+				self.last_click = Some((pos, self.clock));
+				return;
+			}
+		}
+		self.frame.local_slide(&self.image.project_pos(&pos).coords);
+	}
+	/// Orbit algorithm, see [`Self::set_orbit_algorithm()`].
+	#[must_use]
+	pub fn orbit_algorithm(&self) -> OrbitAlgorithm {
+		self.orbit_algorithm
+	}
+	/// Sets orbit algorithm mapping cursor or one-finger movement to rotations.
+	///
+	/// Default is [`OrbitAlgorithm::Trackball`].
+	pub fn set_orbit_algorithm(&mut self, orbit_algorithm: OrbitAlgorithm) {
+		self.discard_orbit();
+		self.orbit_algorithm = orbit_algorithm;
+	}
+	/// Scene wrt enclosing viewing frustum, e.g., to replicate projection math elsewhere.
+	#[must_use]
+	pub fn scene(&self) -> &Scene<f32> {
+		&self.scene
+	}
+	/// Mutable scene wrt enclosing viewing frustum.
+	///
+	/// Marks the camera dirty, see [`Self::mark_dirty()`], so changes take effect on next update.
+	pub fn scene_mut(&mut self) -> &mut Scene<f32> {
+		self.dirty = true;
+		&mut self.scene
+	}
+	/// Sets clip plane distances from target or eye whether [`Scene::scale()`].
+	///
+	/// Delegates to [`Scene::set_clip_planes()`] marking the camera dirty, see
+	/// [`Self::mark_dirty()`]. Default is `(1e-1, 1e+6)` measured from eye.
+	pub fn set_clip_planes(&mut self, znear: f32, zfar: f32) {
+		self.scene.set_clip_planes(znear, zfar);
+		self.dirty = true;
+	}
+	/// Scene shared with other cameras, see [`Self::set_shared_scene()`].
+	#[must_use]
+	pub fn shared_scene(&self) -> Option<&Rc<RefCell<Scene<f32>>>> {
+		self.shared_scene.as_ref()
+	}
+	/// Sets scene shared with other cameras, e.g., for multi-view setups with identical
+	/// projection parameters.
+	///
+	/// On each update, [`Self::scene`] is synchronized with the shared scene before computing
+	/// the image. If [`Self::scene`] has been changed since the previous update, e.g., by the
+	/// orthographic key, the change is written to the shared scene, otherwise the shared scene is
+	/// read. Hence, editing the shared scene once updates all cameras sharing it on their next
+	/// update. Clones of this camera keep sharing it. Use `None` to stop sharing. Default is
+	/// `None`.
+	pub fn set_shared_scene(&mut self, scene: Option<Rc<RefCell<Scene<f32>>>>) {
+		if let Some(shared) = &scene {
+			self.scene = shared.borrow().clone();
+		}
+		self.shared_scene = scene;
+	}
+	/// Orbit operation handler as escape hatch for behavior not covered otherwise.
+	pub fn orbit_mut(&mut self) -> &mut Orbit<f32> {
+		&mut self.orbit
+	}
+	/// Scale operation handler as escape hatch for behavior not covered otherwise.
+	pub fn scale_mut(&mut self) -> &mut Scale<f32> {
+		&mut self.scale
+	}
+	/// Slide operation handler as escape hatch for behavior not covered otherwise.
+	pub fn slide_mut(&mut self) -> &mut Slide<f32> {
+		&mut self.slide
+	}
+	/// Orbits like dragging the orbit button by vector in pixels from the current cursor
+	/// position.
+	///
+	/// Decouples camera logic from [`kiss3d::event`], e.g., for testing or custom input backends.
+	pub fn apply_orbit_delta(&mut self, vec: Vector2<f32>) {
+		let (pos, max) = (*self.image.pos(), *self.image.max());
+		self.discard_orbit();
+		self.compute_orbit(pos, max);
+		if let Some(rot) = self.compute_orbit(pos + vec, max) {
+			self.frame.local_orbit(&rot.powf(self.orbit_factor()));
+		}
+		self.discard_orbit();
+	}
+	/// Scales distance like scrolling by delta at the current cursor position.
+	///
+	/// The world position under the cursor on the focus plane stays under the cursor for both
+	/// perspective and orthographic projection as the latter's extent scales with the target
+	/// distance as well.
+	///
+	/// Decouples camera logic from [`kiss3d::event`], e.g., for testing or custom input backends.
+	pub fn apply_scroll(&mut self, delta: f32) {
+		let rat = self.zoom_ratio(delta);
+		if let Some(inertia) = self.zoom_inertia.as_mut().filter(|_zoom_inertia| rat > 0.0) {
+			inertia.record(Vector3::x() * rat.ln());
+			self.zoom_scrolled = true;
+			self.zoom_anchor = *self.image.pos();
+		}
+		if self.zoom_smoothing.is_some() && rat > 0.0 {
+			self.zoom_pending += rat.ln();
+			self.zoom_anchor = *self.image.pos();
+		} else {
+			self.frame
+				.local_scale_around(rat, &self.project_pos(*self.image.pos()));
+		}
+	}
+	/// Projects position in screen space onto focus plane wrt current target distance.
+	///
+	/// Unlike [`Image::project_pos()`], does not use the unit per pixel cached by the last
+	/// recompute as it is stale once the target distance has changed since, e.g., by multiple
+	/// zoom operations within one step.
+	fn project_pos(&self, pos: Point2<f32>) -> Point3<f32> {
+		let (_proj, upp) = self
+			.scene
+			.projection_and_upp(self.frame.distance(), self.image.max());
+		self.image
+			.transform_pos(&pos)
+			.coords
+			.scale(upp)
+			.push(0.0)
+			.into()
+	}
+	/// Scale ratio of target distance for scroll delta wrt [`Self::log_zoom()`].
+	fn zoom_ratio(&self, delta: f32) -> f32 {
+		if self.log_zoom {
+			(-delta / self.scale.denominator()).exp()
+		} else {
+			self.scale.compute(delta)
+		}
+	}
+	/// Slides like dragging the slide button by vector in pixels.
+	///
+	/// Decouples camera logic from [`kiss3d::event`], e.g., for testing or custom input backends.
+	pub fn apply_slide(&mut self, vec: Vector2<f32>) {
+		let vec = self.image.project_vec(&-vec) * self.slide_speed;
+		self.frame.local_slide(&vec);
+		if let Some(inertia) = &mut self.pan_inertia {
+			inertia.record(vec);
+		}
+	}
+	/// Releases slide operation carrying on with its momentum wrt pan inertia.
+	fn release_slide(&mut self) {
+		self.slide.discard();
+		if let Some(inertia) = &mut self.pan_inertia {
+			inertia.release(self.max_inertia_velocity);
+		}
+	}
+	/// Orbits, slides, and zooms by gamepad input advanced by frame time `dt` in seconds.
+	///
+	/// Maps the left stick to orbiting and the right stick to sliding, both in the sense of
+	/// dragging the respective button, and the left and right triggers to zooming out and in wrt
+	/// the deadzone and sensitivities of `config`. Call once per frame with input polled from
+	/// any gamepad backend. Any deflection beyond the deadzone counts as consumed user input, i.e.,
+	/// it interrupts animations and inertial motion, resets [`Self::idle_time()`], and pauses
+	/// auto-rotation, see [`Self::set_auto_rotate()`].
+	pub fn handle_gamepad_config(&mut self, input: GamepadInput, dt: f32, config: &GamepadConfig) {
+		let orbit = config
+			.stick(input.left_stick)
+			.component_mul(&config.orbit_sensitivity);
+		let slide = config
+			.stick(input.right_stick)
+			.component_mul(&config.slide_sensitivity);
+		let zoom = config.trigger(input.left_trigger) - config.trigger(input.right_trigger);
+		if orbit == Vector2::zeros() && slide == Vector2::zeros() && zoom == 0.0 {
+			return;
+		}
+		self.interrupt();
+		self.idle = 0.0;
+		self.auto_rotate_paused = true;
+		self.orbit_delta(-orbit.x * dt, orbit.y * dt);
+		let vec = -slide * self.frame.distance() * dt;
+		self.frame.local_slide(&Vector3::new(vec.x, vec.y, 0.0));
+		self.frame.local_scale_around(
+			(zoom * config.zoom_sensitivity * dt).exp(),
+			&Point3::origin(),
+		);
+	}
+	/// Whether point in world space lies inside viewing frustum.
+	///
+	/// Tests the point against the six clip planes of [`Camera::transformation()`] in clip space
+	/// for both orthographic and perspective projection.
+	#[must_use]
+	pub fn in_frustum(&self, point: &Point3<f32>) -> bool {
+		let clip = self.proj_view * point.to_homogeneous();
+		let w = clip.w;
+		let near = if self.reversed_z { 0.0 } else { -w };
+		-w <= clip.x && clip.x <= w && -w <= clip.y && clip.y <= w && near <= clip.z && clip.z <= w
+	}
+	/// Eight corners of viewing frustum in world space.
+	///
+	/// Ordered as near clip plane corners followed by far clip plane corners where each is ordered
+	/// counterclockwise as seen from the eye starting at the bottom left, i.e., bottom left,
+	/// bottom right, top right, and top left. Works for both orthographic and perspective
+	/// projection. Far corners are at infinity with [`Self::infinite_far()`].
+	#[must_use]
+	pub fn frustum_corners(&self) -> [Point3<f32>; 8] {
+		let (near, far) = if self.reversed_z {
+			(1.0, 0.0)
+		} else {
+			(-1.0, 1.0)
+		};
+		let ndc = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)];
+		let mut corners = [Point3::origin(); 8];
+		for (corner, (z, (x, y))) in corners.iter_mut().zip(
+			ndc.iter()
+				.map(|&xy| (near, xy))
+				.chain(ndc.iter().map(|&xy| (far, xy))),
+		) {
+			let pos = self.proj_view_inv * Point3::new(x, y, z).to_homogeneous();
+			*corner = Point3::from(pos.xyz() / pos.w);
+		}
+		corners
+	}
+	/// World units the viewport spans vertically on focus plane at target distance.
+	///
+	/// Evaluates the current projection inclusive [`Self::aspect_override()`] and
+	/// [`Self::letterbox()`], that is `2 * distance * tan(fov_y / 2)` under perspective and the
+	/// orthographic extent under orthographic projection, e.g., as basis for scale bars.
+	#[must_use]
+	pub fn visible_height_at_target(&self) -> f32 {
+		let height = 2.0 / self.proj[(1, 1)].abs();
+		if self.scene.ortho() && self.projection_screen.is_none() {
+			height
+		} else {
+			height * self.frame.distance()
+		}
+	}
+	/// World units one pixel spans on focus plane at target distance.
+	///
+	/// Under perspective projection it is evaluated at the target's depth, under orthographic
+	/// projection it is the same for any depth. Useful for drawing handles of fixed size in
+	/// pixels.
+	#[must_use]
+	pub fn world_per_pixel(&self) -> f32 {
+		self.image.upp()
+	}
+	/// Picking ray through position in pixels of screen space.
+	///
+	/// Returns ray origin on near clip plane and ray direction in world space. The position's
+	/// origin is the top left corner of the window like for cursor positions, see
+	/// [`Self::set_viewport()`].
+	#[must_use]
+	pub fn ray(&self, pixel: Point2<f32>) -> (Point3<f32>, Unit<Vector3<f32>>) {
+		let (pixel, max) = (pixel - self.origin().coords, self.image.max());
+		let x = 2.0 * pixel.x / max.x - 1.0;
+		let y = 1.0 - 2.0 * pixel.y / max.y;
+		let (near, mid) = if self.reversed_z {
+			(1.0, 0.5)
+		} else {
+			(-1.0, 0.0)
+		};
+		// Unprojects midway in NDC instead of far clip plane which might be at infinity.
+		let unproject = |z: f32| {
+			let pos = self.proj_view_inv * Point3::new(x, y, z).to_homogeneous();
+			Point3::from(pos.xyz() / pos.w)
+		};
+		let origin = unproject(near);
+		let dir = Unit::try_new(unproject(mid) - origin, f32::EPSILON)
+			.unwrap_or_else(|| -self.frame.roll_axis());
+		(origin, dir)
+	}
+	/// Picking ray through center of viewport, see [`Self::ray()`].
+	///
+	/// Returns ray origin at the center of the near clip plane and the view direction.
+	#[must_use]
+	pub fn center_ray(&self) -> (Point3<f32>, Unit<Vector3<f32>>) {
+		self.ray(self.origin() + self.image.max().coords * 0.5)
+	}
+	/// World position where picking ray through position in pixels hits horizontal plane.
+	///
+	/// The plane is at height `plane_y` along the world's y-axis, see [`Self::plane_point()`].
+	#[must_use]
+	pub fn ground_point(&self, pixel: Point2<f32>, plane_y: f32) -> Option<Point3<f32>> {
+		self.plane_point(pixel, Point3::new(0.0, plane_y, 0.0), Vector3::y())
+	}
+	/// World position where picking ray through position in pixels hits plane.
+	///
+	/// The plane passes through `plane_point` orthogonal to `plane_normal` of any orientation,
+	/// e.g., for dragging on a construction plane, see [`Self::ray()`]. Returns `None` if the ray
+	/// is parallel to or points away from the plane.
+	#[must_use]
+	pub fn plane_point(
+		&self,
+		pixel: Point2<f32>,
+		plane_point: Point3<f32>,
+		plane_normal: Vector3<f32>,
+	) -> Option<Point3<f32>> {
+		let (origin, dir) = self.ray(pixel);
+		let dot = dir.dot(&plane_normal);
+		let t = (plane_point - origin).dot(&plane_normal) / dot;
+		(dot.abs() > f32::EPSILON && t >= 0.0).then(|| origin + dir.into_inner() * t)
+	}
+	/// View matrix as array in column-major order.
+	///
+	/// Element `i + 4 * j` is row `i` of column `j` like expected by `glUniformMatrix4fv()` with
+	/// `transpose` set to `false`.
+	#[must_use]
+	pub fn view_array(&self) -> [f32; 16] {
+		let mut array = [0.0; 16];
+		array.copy_from_slice(self.image.view().as_slice());
+		array
+	}
+	/// Projection matrix as array in column-major order, see [`Self::view_array()`].
+	#[must_use]
+	pub fn projection_array(&self) -> [f32; 16] {
+		let mut array = [0.0; 16];
+		array.copy_from_slice(self.proj.as_slice());
+		array
+	}
+	/// Uploads projection and view of eye of stereo pair to shaders like [`Camera::upload()`].
+	///
+	/// Translates the eye by half of `eye_separation` in world units along the camera's x-axis
+	/// and shifts the viewing frustum off-axis such that the focus plane at the target has zero
+	/// parallax, working for both perspective and orthographic projection. Render the scene once
+	/// per eye, e.g., with color masks for red/cyan anaglyphs.
+	pub fn upload_anaglyph(
+		&self,
+		eye_separation: f32,
+		which: Eye,
+		proj: &mut ShaderUniform<Matrix4<f32>>,
+		view: &mut ShaderUniform<Matrix4<f32>>,
+	) {
+		let side = match which {
+			Eye::Left => -0.5,
+			Eye::Right => 0.5,
+		};
+		let mut eye_proj = self.proj;
+		let shift = side * eye_separation * eye_proj[(0, 0)].signum();
+		eye_proj[(0, 2)] -= eye_proj[(0, 0)] * shift / self.frame.distance();
+		let eye_view =
+			Matrix4::new_translation(&Vector3::new(-shift, 0.0, 0.0)) * self.image.view();
+		proj.upload(&eye_proj);
+		view.upload(&eye_view);
+	}
+	/// Handedness of world coordinate system, see [`Self::set_handedness()`].
+	#[must_use]
+	pub fn handedness(&self) -> Handedness {
+		self.handedness
+	}
+	/// Sets handedness of world coordinate system.
+	///
+	/// With [`Handedness::LeftHanded`], the projection mirrors the x-axis in camera space and
+	/// cursor and finger positions are mirrored horizontally accordingly, so interactions follow
+	/// the cursor on screen. Default is [`Handedness::RightHanded`].
+	pub fn set_handedness(&mut self, handedness: Handedness) {
+		self.handedness = handedness;
+		self.dirty = true;
+	}
+	/// Whether cursor and finger positions have a flipped y-axis, see [`Self::set_flip_y()`].
+	#[must_use]
+	pub fn flip_y(&self) -> bool {
+		self.flip_y
+	}
+	/// Sets whether cursor and finger positions have a flipped y-axis.
+	///
+	/// By default, positions of [`WindowEvent`]s are in pixels with the origin at the top left
+	/// corner of the window and the y-axis pointing down like passed by [`kiss3d`]. If enabled,
+	/// positions are expected with the origin at the bottom left corner and the y-axis pointing
+	/// up, e.g., when embedding the view in a user interface with this convention. Then the
+	/// origin of [`Self::viewport()`] is measured from the bottom left corner as well. Other
+	/// positions, e.g., of [`Self::ray()`], are unaffected. Default is `false`.
+	pub fn set_flip_y(&mut self, flip_y: bool) {
+		self.flip_y = flip_y;
+	}
+	/// Applies boundary conditions and computes image.
+	///
+	/// Skipped unless frame, scene, or image size changed since the last computation or
+	/// [`Self::mark_dirty()`] has been invoked.
+	fn compute(&mut self) {
+		if let Some(shared) = &self.shared_scene {
+			let changed = self
+				.computed
+				.as_ref()
+				.is_some_and(|(_frame, scene, _max)| *scene != self.scene);
+			if changed {
+				shared.borrow_mut().clone_from(&self.scene);
+			} else {
+				self.scene.clone_from(&shared.borrow());
+			}
+		}
+		let max = *self.image.max();
+		if !self.dirty
+			&& self.computed.as_ref().is_some_and(|(frame, scene, old)| {
+				*frame == self.frame && *scene == self.scene && *old == max
+			}) {
+			return;
+		}
+		self.apply_clamp();
+		if let Some(filter) = self.frame_filter {
+			self.frame = filter(self.frame.clone());
+		}
+		self.image.compute(self.frame.clone(), self.scene.clone());
+		self.compute_projection();
+		self.computed = Some((self.frame.clone(), self.scene.clone(), max));
+		self.dirty = false;
+		self.view_changed = true;
+	}
+	/// Sets frame and applies boundary conditions at once, see [`Self::apply_clamp()`].
+	fn set_clamped(&mut self, frame: Frame<f32>) {
+		self.frame = frame;
+		self.apply_clamp();
+	}
+	/// Applies [`Self::clamp`] and other boundary conditions like [`Self::pan_bounds()`].
+	///
+	/// Invokes [`Self::on_clamp_hit()`] for each boundary condition which changed the frame.
+	fn apply_clamp(&mut self) {
+		let frame = self.frame.clone();
+		self.frame = self.clamp.compute(self.frame.clone(), &self.scene);
+		let distance = frame != self.frame;
+		let pan = self.clamp_into_pan_bounds();
+		let pitch = self.clamp_above_ground();
+		let distance = self.clamp_ortho_extent() || distance;
+		let distance = self.clamp_beyond_near() || distance;
+		let distance = self.clamp_out_of_sphere() || distance;
+		if let Some(inertia) = self.zoom_inertia.as_mut().filter(|_zoom_inertia| distance) {
+			inertia.discard();
+		}
+		if let Some(callback) = self.on_clamp_hit {
+			for (hit, kind) in [
+				(distance, ClampKind::Distance),
+				(pan, ClampKind::Pan),
+				(pitch, ClampKind::Pitch),
+			] {
+				if hit {
+					callback(kind);
+				}
+			}
+		}
+	}
+	/// Computes projection matrix and its transformations wrt projection options.
+	fn compute_projection(&mut self) {
+		let mut proj = if let Some(screen) = self.projection_screen {
+			self.screen_projection(screen)
+		} else if let Some(aspect) = self.aspect_override {
+			let max = Point2::new(self.image.max().y * aspect, self.image.max().y);
+			let mut proj = self.scene.projection_and_upp(self.frame.distance(), &max).0;
+			if self.letterbox {
+				let ratio = aspect / (self.image.max().x / self.image.max().y);
+				if ratio < 1.0 {
+					proj.row_mut(0).scale_mut(ratio);
+				} else {
+					proj.row_mut(1).scale_mut(ratio.recip());
+				}
+			}
+			proj
+		} else {
+			*self.image.projection()
+		};
+		if self.infinite_far && !self.scene.ortho() && self.projection_screen.is_none() {
+			let (znear, _zfar) = self.scene.clip_planes(self.frame.distance());
+			proj[(2, 2)] = -1.0;
+			proj[(2, 3)] = -2.0 * znear;
+		}
+		if self.reversed_z {
+			// Maps clip depth `z` to `(w - z) / 2`, i.e., `[-1, 1]` to `[1, 0]`.
+			let row = (proj.row(3) - proj.row(2)) * 0.5;
+			proj.set_row(2, &row);
+		}
+		if self.handedness == Handedness::LeftHanded {
+			proj.column_mut(0).neg_mut();
+		}
+		self.proj = proj;
+		self.proj_view = proj * self.image.view();
+		if let Some(inv) = self.proj_view.try_inverse() {
+			self.proj_view_inv = inv;
+		}
+	}
+	/// Computes orbit rotation in camera space wrt orbit algorithm.
+	fn compute_orbit(&mut self, pos: Point2<f32>, max: Point2<f32>) -> Option<UnitQuaternion<f32>> {
+		match self.orbit_algorithm {
+			OrbitAlgorithm::Trackball => self.orbit.compute(&pos, &max),
+			OrbitAlgorithm::Arcball => self.arcball.compute(pos, max),
+		}
+	}
+	/// Orbits by rotation in camera space around pivot, if any, recording it for inertia.
+	fn orbit_by(&mut self, rot: &UnitQuaternion<f32>) {
+		if let Some(pivot) = self.orbit_pivot {
+			let pos = rotation(&self.frame).inverse() * (pivot - self.frame.target());
+			self.frame.local_orbit_around(rot, &pos.into());
+		} else {
+			self.frame.local_orbit(rot);
+		}
+		if let Some(inertia) = &mut self.orbit_inertia {
+			inertia.record(rot.scaled_axis());
+		}
+	}
+	/// Releases orbit operation carrying on with its momentum wrt inertia.
+	fn release_orbit(&mut self) {
+		self.discard_orbit();
+		if let Some(inertia) = &mut self.orbit_inertia {
+			inertia.release(self.max_inertia_velocity);
+		}
+	}
+	/// Discards orbit operation handlers.
+	fn discard_orbit(&mut self) {
+		self.orbit_pivot = None;
+		self.orbit.discard();
+		self.arcball.discard();
+	}
+	/// Releases cursor grabbed in first person view unless fingers are touching.
+	fn release_cursor(&self, canvas: &dyn Cursor) {
+		if self.touch.fingers() == 0 {
+			let mid = self.origin() + self.image.max().coords * 0.5;
+			canvas.set_cursor_position(mid.x.into(), mid.y.into());
+			canvas.hide_cursor(false);
+			canvas.set_cursor_grab(false);
+		}
+	}
+	/// Mirrors position in screen space horizontally wrt handedness and vertically wrt flip.
+	fn mirror(&self, mut pos: Point2<f32>) -> Point2<f32> {
+		if self.handedness == Handedness::LeftHanded {
+			pos.x = self.image.max().x - pos.x;
+		}
+		if self.flip_y {
+			pos.y = self.image.max().y - pos.y;
+		}
+		pos
+	}
+	/// Rotation from positive y-axis to world-up axis.
+	fn up_rotation(&self) -> UnitQuaternion<f32> {
+		UnitQuaternion::rotation_between_axis(&Vector3::y_axis(), &self.up).unwrap_or_else(|| {
+			UnitQuaternion::from_axis_angle(&Vector3::x_axis(), core::f32::consts::PI)
+		})
+	}
+	/// Chains custom input map in front of the default behavior.
+	///
+	/// Input maps are invoked in order of pushing, see [`InputMap::handle()`]. Events consumed by
+	/// none of them are handled by [`Self::handle_default_event()`]. The input map may be shared
+	/// with other cameras or the application, e.g., to inspect its state. Clones of this camera
+	/// share its input maps instead of cloning them.
+	pub fn push_input_map(&mut self, map: Rc<RefCell<dyn InputMap>>) {
+		self.input_maps.push(map);
+	}
+	/// Removes all custom input maps leaving the default behavior.
+	pub fn clear_input_maps(&mut self) {
+		self.input_maps.clear();
+	}
+	/// Like [`Camera::handle_event()`] but returns whether the event was consumed.
+	///
+	/// An event is consumed if it is consumed by any input map, see [`Self::push_input_map()`],
+	/// or by the default behavior.
+	pub fn handle_event_consumed(&mut self, canvas: &Canvas, event: &WindowEvent) -> bool {
+		if core::mem::take(&mut self.release_pending) {
+			self.release_cursor(canvas);
+		}
+		let mut maps = core::mem::take(&mut self.input_maps);
+		let consumed = maps
+			.iter()
+			.any(|map| map.borrow_mut().handle(self, canvas, event));
+		maps.append(&mut self.input_maps);
+		self.input_maps = maps;
+		self.handle_event_with(canvas, event, consumed)
+	}
+	/// Handles event by default behavior unless consumed by input maps and tracks idle time.
+	fn handle_event_with(
+		&mut self,
+		canvas: &dyn Cursor,
+		event: &WindowEvent,
+		consumed: bool,
+	) -> bool {
+		let consumed = consumed || self.handle_default(canvas, event);
+		if let WindowEvent::Touch(..)
+		| WindowEvent::MouseButton(..)
+		| WindowEvent::CursorPos(..)
+		| WindowEvent::Scroll(..)
+		| WindowEvent::Key(..) = event
+		{
+			self.idle = 0.0;
+		}
+		let toggle = matches!(*event, WindowEvent::Key(key, _action, _modifiers)
+			if Some(key) == self.input.auto_rotate_key());
+		if consumed && !toggle {
+			self.auto_rotate_paused = true;
+		}
+		consumed
+	}
+	/// Handles event by default behavior wrt [`Self::input`] and returns whether it was consumed.
+	///
+	/// An event is consumed if it engages or releases a camera operation, e.g., pressing or
+	/// releasing [`Input::orbit_button()`] with its modifiers or moving the cursor while
+	/// orbiting. Framebuffer size changes are never consumed as they concern the whole
+	/// application. See [`DefaultInputMap`].
+	pub fn handle_default_event(&mut self, canvas: &Canvas, event: &WindowEvent) -> bool {
+		self.handle_default(canvas, event)
+	}
+	fn handle_default(&mut self, canvas: &dyn Cursor, event: &WindowEvent) -> bool {
+		match *event {
+			WindowEvent::Touch(id, x, y, action, modifiers) => {
+				self.handle_touch(canvas, id, x, y, action, modifiers)
+			}
+			WindowEvent::MouseButton(button, action, modifiers) => {
+				self.handle_mouse_button(canvas, button, action, modifiers)
+			}
+			WindowEvent::CursorPos(x, y, modifiers) => {
+				self.handle_cursor_pos(canvas, x, y, modifiers)
+			}
+			WindowEvent::Scroll(dx, dy, modifiers) => self.handle_scroll(canvas, dx, dy, modifiers),
+			WindowEvent::Key(key, action, modifiers) => {
+				self.handle_key(canvas, key, action, modifiers)
+			}
+			WindowEvent::FramebufferSize(w, h) => self.handle_framebuffer_size(canvas, w, h),
+			_ => false,
+		}
+	}
+	fn handle_touch(
+		&mut self,
+		_canvas: &dyn Cursor,
+		id: u64,
+		x: f64,
+		y: f64,
+		action: TouchAction,
+		modifiers: Modifiers,
+	) -> bool {
+		#[allow(clippy::cast_possible_truncation)]
+		let pos = self.mirror(Point2::new(x as f32, y as f32) - self.origin().coords);
+		match action {
+			TouchAction::Start | TouchAction::Move => {
+				if action == TouchAction::Start {
+					self.interrupt();
+					self.slide.discard();
+					self.touch_accum = (Vector2::zeros(), 0.0);
+					self.touch_engaged = [false; 2];
+					self.tap.start(id, pos, self.clock);
+				} else {
+					self.tap.compute(id, pos, self.tap_tolerance);
+				}
+				if let Some((num, pos, rot, rat)) = self.touch.compute(Some(id), pos, 0) {
+					if self.first.enabled() {
+						if let Some(vec) = self.slide.compute(pos) {
+							let vec = vec * self.input.first_look_sensitivity();
+							if let Some((pitch, yaw, yaw_axis)) =
+								self.first.compute(&vec, self.image.max())
+							{
+								self.frame.look_around(pitch, yaw, yaw_axis);
+							}
+						}
+					} else {
+						if num > 1 {
+							self.compute_touch_gesture(num, pos, rot, rat);
+						} else if self.touch_orbit_enabled {
+							if let Some(rot) = self.compute_orbit(pos, *self.image.max()) {
+								self.orbit_by(&rot.powf(self.orbit_factor()));
+							}
+						}
+					}
+				}
+			}
+			TouchAction::End | TouchAction::Cancel => {
+				if action == TouchAction::Cancel {
+					self.tap.invalidate();
+				}
+				let tap = self.tap.discard(id, self.clock, self.tap_window);
+				let pos = self.touch.discard(Some(id)).map(|(_num, pos)| pos);
+				if let Some(action) = self.two_finger_tap.filter(|_action| tap) {
+					action(self);
+				} else if let Some(pos) = pos {
+					self.focus_on_click(pos, modifiers);
+				}
+				if action == TouchAction::End && self.touch.fingers() == 0 {
+					self.release_orbit();
+				} else {
+					self.discard_orbit();
+				}
+				if action == TouchAction::End && self.touch.fingers() == 1 {
+					self.release_slide();
+					if let Some(inertia) = &mut self.zoom_inertia {
+						inertia.release(self.max_inertia_velocity);
+					}
+				} else {
+					self.slide.discard();
+				}
+			}
+		}
+		true
+	}
+	/// Slides, rolls, and scales by multi-finger gesture wrt deadzones.
+	fn compute_touch_gesture(&mut self, num: usize, pos: Point2<f32>, rot: f32, rat: f32) {
+		if let Some(vec) = self.slide.compute(pos) {
+			self.touch_accum.0 += vec;
+			self.touch_engaged[0] |= self.touch_accum.0.norm() > self.touch_pan_threshold;
+			if self.touch_engaged[0] {
+				self.apply_slide(-vec);
+			}
+		}
+		if num != 2 || rat <= 0.0 {
+			return;
+		}
+		self.touch_accum.1 += rat.ln();
+		self.touch_engaged[1] |= self.touch_accum.1.abs() > self.touch_pinch_threshold.ln_1p();
+		let pixel = pos;
+		let pos = self.image.project_pos(&pos);
+		if self.touch_roll_enabled {
+			let rot = UnitQuaternion::from_axis_angle(&self.frame.local_roll_axis(), rot);
+			self.frame.local_orbit_around(&rot, &pos);
+		}
+		if self.touch_engaged[1] {
+			if let Some(inertia) = &mut self.zoom_inertia {
+				inertia.record(Vector3::x() * rat.ln());
+				self.zoom_anchor = pixel;
+			}
+			self.frame.local_scale_around(rat, &pos);
+		}
+	}
+	fn handle_mouse_button(
+		&mut self,
+		_canvas: &dyn Cursor,
+		button: MouseButton,
+		action: Action,
+		modifiers: Modifiers,
+	) -> bool {
+		if self.first.enabled() {
+			return false;
+		}
+		let is_eq = |gate: Option<Modifiers>| gate.is_none_or(|m| m == modifiers);
+		let bound = [
+			self.input.orbit_button(),
+			self.input.slide_button(),
+			self.input.scale_button(),
+		];
+		if action == Action::Press && bound.contains(&Some(button)) {
+			self.interrupt();
+		}
+		// Events are only consumed if they engage or release an operation.
+		let mut consumed = false;
+		if Some(button) == self.input.orbit_button() {
+			if action == Action::Press {
+				self.dragged = false;
+				if is_eq(self.input.orbit_modifiers()) {
+					if !self.orbiting {
+						self.touch.compute(None, *self.image.pos(), 0);
+						self.orbiting = true;
+						self.drag_pending =
+							Some(*self.image.pos()).filter(|_pos| self.drag_threshold > 0.0);
+						if self.orbit_around_cursor {
+							let pos = self.image.project_pos(self.image.pos());
+							self.orbit_pivot =
+								Some(self.frame.target() + rotation(&self.frame) * pos.coords);
+						}
+					}
+					consumed = true;
+				}
+			} else {
+				self.release_orbit();
+				self.drag_pending = None;
+				if core::mem::take(&mut self.orbiting) {
+					let pos = self.touch.discard(None).map(|(_num, pos)| pos);
+					if let Some(pos) = pos.filter(|_pos| !self.dragged) {
+						self.focus_on_click(pos, modifiers);
+					}
+					consumed = true;
+				}
+			}
+		}
+		if Some(button) == self.input.slide_button() {
+			if action == Action::Press {
+				if is_eq(self.input.slide_modifiers()) {
+					self.slide.compute(*self.image.pos());
+					self.slide_pending =
+						Some(*self.image.pos()).filter(|_pos| self.slide_threshold > 0.0);
+					self.sliding = true;
+					consumed = true;
+				}
+			} else {
+				self.release_slide();
+				let pending = self.slide_pending.take().is_some();
+				consumed |= core::mem::take(&mut self.sliding) && !pending;
+			}
+		}
+		if Some(button) == self.input.scale_button() {
+			if action == Action::Press {
+				if is_eq(self.input.scale_modifiers()) {
+					self.drag.compute(*self.image.pos());
+					self.scaling = true;
+					consumed = true;
+				}
+			} else {
+				self.drag.discard();
+				consumed |= core::mem::take(&mut self.scaling);
+			}
+		}
+		consumed
+	}
+	fn handle_cursor_pos(
+		&mut self,
+		canvas: &dyn Cursor,
+		x: f64,
+		y: f64,
+		modifiers: Modifiers,
+	) -> bool {
+		let pos = Point2::new(x, y);
+		let is_eq = |old| old == pos || old == Point2::new(pos.x.floor(), pos.y.floor());
+		if self.mouse.replace(pos).is_none_or(is_eq) {
+			return false;
+		}
+		let (pos, max) = (pos.cast() - self.origin().coords, *self.image.max());
+		let (min, max_x, max_y) = (
+			self.origin().cast::<f64>(),
+			f64::from(self.origin().x + max.x),
+			f64::from(self.origin().y + max.y),
+		);
+		if self.first.enabled() {
+			if self.touch.fingers() == 0 {
+				if let Some(vec) = self.slide.compute(pos) {
+					canvas.hide_cursor(true);
+					canvas.set_cursor_grab(true);
+					let mut vec = vec * self.input.first_look_sensitivity();
+					if self.handedness == Handedness::LeftHanded {
+						vec.x = -vec.x;
+					}
+					if self.flip_y {
+						vec.y = -vec.y;
+					}
+					if let Some((pitch, yaw, yaw_axis)) = self.first.compute(&vec, &max) {
+						self.frame.look_around(pitch, yaw, yaw_axis);
+					}
+				}
+				if pos.y <= 0.0 {
+					canvas.set_cursor_position(x, max_y - 2.0);
+					self.slide.discard();
+				}
+				if pos.x <= 0.0 {
+					canvas.set_cursor_position(max_x - 2.0, y);
+					self.slide.discard();
+				}
+				if pos.x >= max.x - 1.0 {
+					canvas.set_cursor_position(min.x + 1.0, y);
+					self.slide.discard();
+				}
+				if pos.y >= max.y - 1.0 {
+					canvas.set_cursor_position(x, min.y + 1.0);
+					self.slide.discard();
+				}
+				true
+			} else {
+				false
+			}
+		} else {
+			let pos = self.mirror(pos);
+			let orbit = self.input.orbit_button().is_some_and(|button| {
+				canvas.get_mouse_button(button) == Action::Press
+					&& self.input.orbit_modifiers().is_none_or(|m| m == modifiers)
+			});
+			let slide = self.input.slide_button().is_some_and(|button| {
+				canvas.get_mouse_button(button) == Action::Press
+					&& self.input.slide_modifiers().is_none_or(|m| m == modifiers)
+			});
+			let scale = self.input.scale_button().is_some_and(|button| {
+				canvas.get_mouse_button(button) == Action::Press
+					&& self.input.scale_modifiers().is_none_or(|m| m == modifiers)
+			});
+			let pos = match self.input_smoothing {
+				Some(radius) if orbit || slide || scale => self.smoothing.compute(pos, radius),
+				_ => {
+					self.smoothing.discard();
+					pos
+				}
+			};
+			self.image.set_pos(pos);
+			if orbit && slide {
+				self.discard_orbit();
+				self.slide.discard();
+			}
+			// Operations only engage while their modifiers are pressed. Releasing them stops the
+			// operation without resuming a stale drag when pressing them again.
+			// Re-engaging is a drag, hence releasing afterwards is no click.
+			if orbit {
+				self.dragged |= !core::mem::replace(&mut self.orbiting, true);
+				self.compute_drag(pos, max);
+			} else if core::mem::take(&mut self.orbiting) {
+				self.dragged = true;
+				self.discard_orbit();
+				self.touch.discard(None);
+				self.drag_pending = None;
+			}
+			self.sliding = slide;
+			if slide {
+				self.compute_slide(pos);
+			} else {
+				self.slide.discard();
+				self.slide_pending = None;
+			}
+			self.scaling = scale;
+			if scale {
+				self.compute_scale(pos);
+			} else {
+				self.drag.discard();
+			}
+			orbit || slide || scale
+		}
+	}
+	/// Scales distance by vertical cursor movement.
+	fn compute_scale(&mut self, pos: Point2<f32>) {
+		if let Some(vec) = self.drag.compute(pos) {
+			let rat = self.zoom_ratio(vec.y);
+			if rat > 0.0 {
+				self.frame.local_scale_around(rat, &Point3::origin());
+			}
+		}
+	}
+	/// Orbits to cursor position once moved beyond [`Self::drag_threshold()`].
+	fn compute_drag(&mut self, pos: Point2<f32>, max: Point2<f32>) {
+		if let Some(old) = self.drag_pending {
+			if (pos - old).norm() > self.drag_threshold {
+				self.drag_pending = None;
+			}
+		}
+		if self.drag_pending.is_none() {
+			if let Some(pos) = self.touch.compute(None, pos, 0).map(|val| val.1) {
+				if let Some(rot) = self.compute_orbit(pos, max) {
+					self.orbit_by(&rot.powf(self.orbit_factor()));
+				}
+			}
+		}
+	}
+	/// Slides to cursor position once moved beyond [`Self::slide_threshold()`].
+	fn compute_slide(&mut self, pos: Point2<f32>) {
+		if let Some(old) = self.slide_pending {
+			if (pos - old).norm() > self.slide_threshold {
+				self.slide_pending = None;
+			}
+		}
+		if self.slide_pending.is_none() {
+			if let Some(vec) = self.slide.compute(pos) {
+				self.apply_slide(-vec);
+			}
+		}
+	}
+	fn handle_scroll(
+		&mut self,
+		_canvas: &dyn Cursor,
+		dx: f64,
+		dy: f64,
+		_modifiers: Modifiers,
+	) -> bool {
+		self.interrupt();
+		if self.touchpad_scroll_pans {
+			#[allow(clippy::cast_possible_truncation)]
+			self.apply_slide(Vector2::new(dx as f32, -dy as f32));
+		} else {
+			#[allow(clippy::cast_possible_truncation)]
+			self.apply_scroll(dy as f32);
+		}
+		true
+	}
+	/// Tracks keys of continuous keyboard operations and returns whether key is bound.
+	fn handle_held_key(&mut self, key: Key, action: Action) -> bool {
+		let pressed = if let Some(dir) = Direction::ALL
+			.into_iter()
+			.find(|&dir| Some(key) == self.input.orbit_key(dir))
+		{
+			&mut self.orbit_keys[dir as usize]
+		} else if let Some(dir) = Direction::ALL
+			.into_iter()
+			.find(|&dir| Some(key) == self.input.pan_key(dir))
+		{
+			&mut self.pan_keys[dir as usize]
+		} else if let Some(index) = [self.input.zoom_in_key(), self.input.zoom_out_key()]
+			.into_iter()
+			.position(|zoom_key| Some(key) == zoom_key)
+		{
+			&mut self.zoom_keys[index]
+		} else {
+			return false;
+		};
+		*pressed = action == Action::Press;
+		if action == Action::Press {
+			self.interrupt();
+		}
+		true
+	}
+	fn handle_key(
+		&mut self,
+		canvas: &dyn Cursor,
+		key: Key,
+		action: Action,
+		_modifiers: Modifiers,
+	) -> bool {
+		let fly_move = FlyMove::ALL
+			.into_iter()
+			.find(|&mv| Some(key) == self.input.fly_move_key(mv));
+		if let Some((fly, mv)) = self.fly.as_mut().zip(fly_move) {
+			fly.set(mv, action == Action::Press);
+			true
+		} else if let Some(fly) = self
+			.fly
+			.as_mut()
+			.filter(|_fly| Some(key) == self.input.fly_sprint_key())
+		{
+			fly.set_sprint(action == Action::Press);
+			true
+		} else if self.handle_held_key(key, action) {
+			true
+		} else if let Some(index) = [self.input.first_up_key(), self.input.first_down_key()]
+			.into_iter()
+			.position(|first_key| Some(key) == first_key)
+		{
+			self.first_vertical[index] = action == Action::Press;
+			self.first.enabled()
+		} else if Some(key) == self.input.fly_key() {
+			if action == Action::Press {
+				if self.fly.is_some() {
+					self.set_fly_mode(false);
+					self.release_cursor(canvas);
+				} else {
+					self.set_fly_mode(true);
+				}
+			}
+			true
+		} else if Some(key) == self.input.first_key() && self.fly.is_none() {
+			if action == Action::Press {
+				self.set_first_person(true);
+			} else {
+				self.set_first_person(false);
+				self.slide.discard();
+				self.release_pending = false;
+				self.release_cursor(canvas);
+			}
+			true
+		} else if Some(key) == self.input.ortho_key() {
+			if action == Action::Press {
+				self.set_ortho(!self.is_ortho());
+				if let Some(callback) = self.on_projection_changed {
+					callback(self.is_ortho());
+				}
+			}
+			true
+		} else if Some(key) == self.input.reset_key() {
+			if action == Action::Press {
+				self.reset_frame();
+			}
+			true
+		} else if Some(key) == self.input.reset_rotation_key() {
+			if action == Action::Press {
+				self.reset_rotation();
+			}
+			true
+		} else if Some(key) == self.input.reset_zoom_key() {
+			if action == Action::Press {
+				self.reset_zoom();
+			}
+			true
+		} else if Some(key) == self.input.auto_rotate_key() {
+			if action == Action::Press {
+				self.toggle_auto_rotate();
+			}
+			true
+		} else if let Some(index) = [
+			self.input.next_bookmark_key(),
+			self.input.prev_bookmark_key(),
+		]
+		.into_iter()
+		.position(|bookmark_key| Some(key) == bookmark_key)
+		{
+			if action == Action::Press {
+				if index == 0 {
+					self.next_bookmark();
+				} else {
+					self.prev_bookmark();
+				}
+			}
+			true
+		} else if let Some(view) = StandardView::ALL
+			.into_iter()
+			.find(|&view| Some(key) == self.input.standard_view_key(view))
+		{
+			if action == Action::Press {
+				if let Some(duration) = self.standard_view_duration {
+					self.animate_to_view(view, duration);
+				} else {
+					self.set_standard_view(view);
+				}
+			}
+			true
+		} else {
+			false
+		}
+	}
+	fn handle_framebuffer_size(&mut self, _canvas: &dyn Cursor, w: u32, h: u32) -> bool {
+		if self.viewport.is_none() {
+			self.image.set_max(Point2::new(w, h).cast());
+		}
+		false
+	}
+}
+
+impl Camera for Trackball {
+	fn clip_planes(&self) -> (f32, f32) {
+		let (znear, zfar) = self.scene.clip_planes(self.frame.distance());
+		if self.infinite_far && (!self.scene.ortho() || self.projection_screen.is_some()) {
+			(znear, f32::INFINITY)
+		} else {
+			(znear, zfar)
+		}
+	}
+	fn view_transform(&self) -> Isometry3<f32> {
+		*self.image.view_isometry()
+	}
+	fn eye(&self) -> Point3<f32> {
+		self.frame.eye()
+	}
+	fn handle_event(&mut self, canvas: &Canvas, event: &WindowEvent) {
+		self.handle_event_consumed(canvas, event);
+	}
+	#[inline]
+	fn upload(
+		&self,
+		_: usize,
+		proj: &mut ShaderUniform<Matrix4<f32>>,
+		view: &mut ShaderUniform<Matrix4<f32>>,
+	) {
+		proj.upload(&self.proj);
+		view.upload(self.image.view());
+	}
+	fn transformation(&self) -> Matrix4<f32> {
+		self.proj_view
+	}
+	fn inverse_transformation(&self) -> Matrix4<f32> {
+		self.proj_view_inv
+	}
+	fn update(&mut self, _: &Canvas) {
+		if !self.stepped {
+			self.step(self.frame_time);
+		}
+		self.stepped = false;
+		self.compute();
+	}
+}
+
+/// Converts vertical to horizontal field of view in radians wrt aspect ratio of width to height.
+///
+/// Inverse of [`fov_h_to_v()`].
+#[must_use]
+pub fn fov_v_to_h(v: f32, aspect: f32) -> f32 {
+	((v * 0.5).tan() * aspect).atan() * 2.0
+}
+
+/// Converts horizontal to vertical field of view in radians wrt aspect ratio of width to height.
+///
+/// Inverse of [`fov_v_to_h()`].
+#[must_use]
+pub fn fov_h_to_v(h: f32, aspect: f32) -> f32 {
+	((h * 0.5).tan() / aspect).atan() * 2.0
+}
+
+/// Blend factor `1 - exp(-dt / smoothing)` of exponential smoothing with time constant.
+///
+/// Returns `1` for non-positive `smoothing`.
+fn blend(smoothing: f32, dt: f32) -> f32 {
+	if smoothing > 0.0 {
+		1.0 - (-dt / smoothing).exp()
+	} else {
+		1.0
 	}
 }
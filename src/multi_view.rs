@@ -0,0 +1,183 @@
+use crate::{
+	animation::{from_parts, rotation},
+	Direction, StandardView, Trackball,
+};
+use kiss3d::{
+	event::{Action, MouseButton, TouchAction, WindowEvent},
+	nalgebra::{Point2, Point3, Vector3},
+	window::Canvas,
+};
+
+/// Synchronized quad-view of trackball cameras sharing target and distance.
+///
+/// Lays out four cameras in a grid of viewports, see [`Trackball::set_viewport()`], in order of
+/// left to right and top to bottom:
+///
+/// Index | View                       | Projection
+/// ----- | -------------------------- | ------------
+/// 0     | [`StandardView::Top`]      | Orthographic
+/// 1     | [`StandardView::Front`]    | Orthographic
+/// 2     | [`StandardView::Left`]     | Orthographic
+/// 3     | [`StandardView::Iso`]      | Perspective
+///
+/// Orthographic views have fixed orientations, that is their orbit button, orbit keys, and keys
+/// changing orientation or projection like [`crate::Input::first_key()`] are unbound and their
+/// one-finger orbit and two-finger roll are disabled, see [`Trackball::set_touch_orbit_enabled()`]
+/// and [`Trackball::set_touch_roll_enabled()`]. Events are dispatched to the camera whose viewport
+/// is under the cursor or finger, which keeps receiving them while dragging. Afterwards, target and
+/// distance of all other cameras are synchronized with it. The application is responsible for
+/// rendering each camera into its viewport.
+#[derive(Clone)]
+pub struct MultiView {
+	views: [Trackball; 4],
+	hovered: Option<usize>,
+	grab: Option<usize>,
+	fingers: usize,
+}
+
+impl MultiView {
+	/// Creates quad-view with target position and target distance from eyes.
+	///
+	/// Lays out the cameras wrt framebuffer size in pixels, see [`Self::layout()`].
+	#[must_use]
+	pub fn new(target: Point3<f32>, distance: f32, size: Point2<f32>) -> Self {
+		let eye = target + Vector3::z() * distance;
+		let views = [
+			StandardView::Top,
+			StandardView::Front,
+			StandardView::Left,
+			StandardView::Iso,
+		]
+		.map(|view| {
+			let mut trackball = Trackball::new(target, &eye, &Vector3::y());
+			trackball.set_standard_view(view);
+			trackball.reset = trackball.frame.clone();
+			if view != StandardView::Iso {
+				trackball.scene.set_ortho(true);
+				trackball.input.rebind_orbit_button(None);
+				for dir in Direction::ALL {
+					trackball.input.rebind_orbit_key(dir, None);
+				}
+				trackball.input.rebind_ortho_key(None);
+				trackball.input.rebind_first_key(None);
+				trackball.input.rebind_fly_key(None);
+				trackball.input.rebind_auto_rotate_key(None);
+				trackball.input.rebind_next_bookmark_key(None);
+				trackball.input.rebind_prev_bookmark_key(None);
+				for view in StandardView::ALL {
+					trackball.input.rebind_standard_view_key(view, None);
+				}
+				trackball.set_touch_orbit_enabled(false);
+				trackball.set_touch_roll_enabled(false);
+			}
+			trackball
+		});
+		let mut multi_view = Self {
+			views,
+			hovered: None,
+			grab: None,
+			fingers: 0,
+		};
+		multi_view.layout(size);
+		multi_view
+	}
+	/// Cameras in order of layout.
+	#[must_use]
+	pub fn views(&self) -> &[Trackball; 4] {
+		&self.views
+	}
+	/// Mutable cameras in order of layout, see [`Self::synchronize()`].
+	pub fn views_mut(&mut self) -> &mut [Trackball; 4] {
+		&mut self.views
+	}
+	/// Lays out cameras in grid of viewports covering framebuffer of size in pixels.
+	///
+	/// Invoked on framebuffer size changes by [`Self::handle_event()`].
+	pub fn layout(&mut self, size: Point2<f32>) {
+		let half = size * 0.5;
+		for (index, view) in self.views.iter_mut().enumerate() {
+			let (col, row) = (index % 2, index / 2);
+			#[allow(clippy::cast_precision_loss)]
+			let origin = Point2::new(half.x * col as f32, half.y * row as f32);
+			view.set_viewport(origin, half);
+		}
+	}
+	/// Synchronizes target and distance of all cameras with the one at index.
+	///
+	/// Invoked by [`Self::handle_event()`], otherwise invoke it after changing a camera via
+	/// [`Self::views_mut()`].
+	pub fn synchronize(&mut self, index: usize) {
+		let target = *self.views[index].frame.target();
+		let distance = self.views[index].frame.distance();
+		for (other, view) in self.views.iter_mut().enumerate() {
+			if other != index {
+				view.frame = from_parts(target, &rotation(&view.frame), distance);
+			}
+		}
+	}
+	/// Index of camera whose viewport contains position in pixels of window.
+	#[must_use]
+	pub fn view_at(&self, pos: Point2<f32>) -> Option<usize> {
+		self.views.iter().position(|view| {
+			let (min, max) = view.viewport();
+			let max = min + max.coords;
+			(min.x..max.x).contains(&pos.x) && (min.y..max.y).contains(&pos.y)
+		})
+	}
+	/// Dispatches event to camera under cursor or finger and returns whether it was consumed.
+	pub fn handle_event(&mut self, canvas: &Canvas, event: &WindowEvent) -> bool {
+		#[allow(clippy::cast_possible_truncation)]
+		let index = match *event {
+			WindowEvent::FramebufferSize(w, h) => {
+				self.layout(Point2::new(w, h).cast());
+				return false;
+			}
+			WindowEvent::CursorPos(x, y, _modifiers) => {
+				if self.grab.is_none() {
+					self.hovered = self.view_at(Point2::new(x as f32, y as f32));
+				}
+				self.grab.or(self.hovered)
+			}
+			WindowEvent::MouseButton(_button, action, _modifiers) => {
+				if action == Action::Press && self.grab.is_none() {
+					self.grab = self.hovered;
+				}
+				let index = self.grab.or(self.hovered);
+				let pressed = [
+					MouseButton::Button1,
+					MouseButton::Button2,
+					MouseButton::Button3,
+				]
+				.into_iter()
+				.any(|button| canvas.get_mouse_button(button) == Action::Press);
+				if action == Action::Release && !pressed {
+					self.grab = None;
+				}
+				index
+			}
+			WindowEvent::Touch(_id, x, y, action, _modifiers) => {
+				if action == TouchAction::Start {
+					if self.fingers == 0 {
+						self.grab = self.view_at(Point2::new(x as f32, y as f32));
+					}
+					self.fingers += 1;
+				}
+				let index = self.grab;
+				if matches!(action, TouchAction::End | TouchAction::Cancel) {
+					self.fingers = self.fingers.saturating_sub(1);
+					if self.fingers == 0 {
+						self.grab = None;
+					}
+				}
+				index
+			}
+			_ => self.grab.or(self.hovered),
+		};
+		let Some(index) = index else {
+			return false;
+		};
+		let consumed = self.views[index].handle_event_consumed(canvas, event);
+		self.synchronize(index);
+		consumed
+	}
+}
@@ -0,0 +1,31 @@
+use kiss3d::nalgebra::Point2;
+
+/// Velocity-adaptive smoothing filter of cursor positions.
+///
+/// Blends each position into the filtered one by the ratio of their distance to the sum of their
+/// distance and the jitter radius. Slow movements within the radius are smoothed out, whereas
+/// fast movements pass through virtually unfiltered without perceptible lag.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Smoothing {
+	pos: Option<Point2<f32>>,
+}
+
+impl Smoothing {
+	/// Computes filtered position wrt jitter radius in pixels.
+	pub fn compute(&mut self, pos: Point2<f32>, radius: f32) -> Point2<f32> {
+		let old = self.pos.unwrap_or(pos);
+		let vec = pos - old;
+		let len = vec.norm();
+		let pos = if len + radius > 0.0 {
+			old + vec * (len / (len + radius))
+		} else {
+			pos
+		};
+		self.pos = Some(pos);
+		pos
+	}
+	/// Forgets filtered position.
+	pub fn discard(&mut self) {
+		self.pos = None;
+	}
+}
@@ -0,0 +1,55 @@
+use kiss3d::nalgebra::Point2;
+
+/// Two-finger tap operation handler.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Tap {
+	fingers: [Option<(u64, Point2<f32>)>; 2],
+	count: usize,
+	time: f32,
+	moved: bool,
+}
+
+impl Tap {
+	/// Captures finger touching down at time in seconds.
+	pub fn start(&mut self, id: u64, pos: Point2<f32>, time: f32) {
+		if self.fingers.iter().all(Option::is_none) {
+			*self = Self {
+				time,
+				..Self::default()
+			};
+		}
+		if let Some(finger) = self.fingers.iter_mut().find(|finger| finger.is_none()) {
+			*finger = Some((id, pos));
+		} else {
+			self.moved = true;
+		}
+		self.count += 1;
+	}
+	/// Invalidates tap if finger moved farther than tolerance in pixels.
+	pub fn compute(&mut self, id: u64, pos: Point2<f32>, tolerance: f32) {
+		for (fid, old) in self.fingers.iter().flatten() {
+			if *fid == id && (pos - old).norm() > tolerance {
+				self.moved = true;
+			}
+		}
+	}
+	/// Releases finger at time in seconds.
+	///
+	/// Returns `true` if releasing the last finger completes a two-finger tap within window in
+	/// seconds.
+	pub fn discard(&mut self, id: u64, time: f32, window: f32) -> bool {
+		for finger in &mut self.fingers {
+			if finger.is_some_and(|(fid, _pos)| fid == id) {
+				*finger = None;
+			}
+		}
+		self.fingers.iter().all(Option::is_none)
+			&& !self.moved
+			&& self.count == 2
+			&& time - self.time <= window
+	}
+	/// Invalidates tap, e.g., on cancelled touch.
+	pub fn invalidate(&mut self) {
+		self.moved = true;
+	}
+}
@@ -0,0 +1,458 @@
+use crate::{
+	animation::rotation, cursor::Cursor, fov_h_to_v, fov_v_to_h, inertia::Inertia, Easing,
+	GamepadConfig, GamepadInput, Handedness, Input, MultiView, StandardView, Trackball,
+};
+use alloc::vec::Vec;
+use core::{cell::RefCell, f32::consts::FRAC_PI_2};
+use kiss3d::{
+	event::{Action, Key, Modifiers, MouseButton, WindowEvent},
+	nalgebra::{Point2, Point3, Vector3},
+};
+
+/// Camera looking from positive z-axis at origin with image of 800 × 600 pixels.
+fn trackball() -> Trackball {
+	let mut trackball = Trackball::new(
+		Point3::origin(),
+		&Point3::new(0.0, 0.0, 10.0),
+		&Vector3::y(),
+	);
+	trackball.image.set_max(Point2::new(800.0, 600.0));
+	trackball.update_dt(0.0);
+	trackball
+}
+
+/// Position in pixels of world point with origin at top left corner.
+fn pixel(trackball: &Trackball, point: &Point3<f32>) -> Point2<f32> {
+	let clip = trackball.proj_view * point.to_homogeneous();
+	let (ndc, max) = (clip.xyz() / clip.w, trackball.image.max());
+	Point2::new((ndc.x + 1.0) * 0.5 * max.x, (1.0 - ndc.y) * 0.5 * max.y)
+}
+
+fn scroll_keeps_point_under_cursor(ortho: bool) {
+	let mut trackball = trackball();
+	trackball.set_ortho(ortho);
+	trackball.update_dt(0.0);
+	let pos = Point2::new(600.0, 150.0);
+	trackball.image.set_pos(pos);
+	let normal = trackball.frame.roll_axis().into_inner();
+	let point = trackball
+		.plane_point(pos, *trackball.frame.target(), normal)
+		.unwrap();
+	let distance = trackball.frame.distance();
+	trackball.apply_scroll(3.0);
+	trackball.update_dt(0.0);
+	assert!((trackball.frame.distance() - distance).abs() > 1e-3);
+	assert!((pixel(&trackball, &point) - pos).norm() < 1e-2);
+}
+
+#[test]
+fn scroll_keeps_point_under_cursor_in_perspective() {
+	scroll_keeps_point_under_cursor(false);
+}
+
+#[test]
+fn scroll_keeps_point_under_cursor_in_ortho() {
+	scroll_keeps_point_under_cursor(true);
+}
+
+/// Normalized device depth of point at distance along view direction.
+fn depth(trackball: &Trackball, distance: f32) -> f32 {
+	let point = trackball.frame.eye() - trackball.frame.roll_axis().into_inner() * distance;
+	let clip = trackball.proj_view * point.to_homogeneous();
+	clip.z / clip.w
+}
+
+#[test]
+fn reversed_z_maps_near_to_one_and_far_to_zero() {
+	let mut trackball = trackball();
+	trackball.set_reversed_z(true);
+	trackball.update_dt(0.0);
+	let (znear, zfar) = trackball.scene.clip_planes(trackball.frame.distance());
+	assert!((depth(&trackball, znear) - 1.0).abs() < 1e-4);
+	assert!(depth(&trackball, zfar).abs() < 1e-4);
+}
+
+#[test]
+fn infinite_far_keeps_distant_points() {
+	let mut trackball = trackball();
+	let point = trackball.frame.eye() - trackball.frame.roll_axis().into_inner() * 1e7;
+	assert!(!trackball.in_frustum(&point));
+	trackball.set_infinite_far(true);
+	trackball.update_dt(0.0);
+	assert!(trackball.in_frustum(&point));
+	assert!((-1.0..=1.0).contains(&depth(&trackball, 1e7)));
+}
+
+#[test]
+fn handedness_mirrors_positive_x_axis() {
+	let mut trackball = trackball();
+	let point = Point3::new(1.0, 0.0, 0.0);
+	let ndc_x = |trackball: &Trackball| {
+		let clip = trackball.proj_view * point.to_homogeneous();
+		clip.x / clip.w
+	};
+	assert!(ndc_x(&trackball) > 0.0);
+	trackball.set_handedness(Handedness::LeftHanded);
+	trackball.update_dt(0.0);
+	assert!(ndc_x(&trackball) < 0.0);
+}
+
+#[test]
+fn idle_update_does_not_recompute_image() {
+	let mut trackball = trackball();
+	let computed = trackball.computed.clone();
+	trackball.view_changed = false;
+	trackball.update_dt(0.0);
+	trackball.update_dt(0.0);
+	assert!(!trackball.dirty);
+	assert!(!trackball.view_changed());
+	assert!(trackball.computed == computed);
+	trackball.apply_scroll(3.0);
+	trackball.update_dt(0.0);
+	assert!(trackball.view_changed());
+	assert!(trackball.computed != computed);
+}
+
+#[test]
+fn fov_conversion_is_identity_at_square_aspect() {
+	for fov in [0.1, 0.5, FRAC_PI_2, 2.5] {
+		assert!((fov_v_to_h(fov, 1.0) - fov).abs() < 1e-6);
+		assert!((fov_h_to_v(fov, 1.0) - fov).abs() < 1e-6);
+	}
+}
+
+#[test]
+fn fov_conversion_widens_with_aspect() {
+	let h = fov_v_to_h(FRAC_PI_2, 2.0);
+	assert!((h - 2.0 * 2.0f32.atan()).abs() < 1e-6);
+	assert!((fov_h_to_v(h, 2.0) - FRAC_PI_2).abs() < 1e-6);
+}
+
+#[test]
+fn fov_conversions_are_inverse() {
+	for aspect in [0.5, 4.0 / 3.0, 16.0 / 9.0] {
+		for fov in [0.1, 0.5, FRAC_PI_2, 2.5] {
+			assert!((fov_h_to_v(fov_v_to_h(fov, aspect), aspect) - fov).abs() < 1e-5);
+			assert!((fov_v_to_h(fov_h_to_v(fov, aspect), aspect) - fov).abs() < 1e-5);
+		}
+	}
+}
+
+#[test]
+fn easings_map_endpoints_onto_themselves() {
+	for easing in Easing::ALL {
+		assert!(easing.apply(0.0).abs() < 1e-6);
+		assert!((easing.apply(1.0) - 1.0).abs() < 1e-6);
+	}
+}
+
+#[test]
+fn easings_are_monotonic() {
+	for easing in Easing::ALL {
+		let mut old = easing.apply(0.0);
+		for step in 1..=100u8 {
+			let new = easing.apply(f32::from(step) / 100.0);
+			assert!(new >= old);
+			old = new;
+		}
+	}
+}
+
+#[test]
+fn symmetric_easings_are_halfway_at_midpoint() {
+	for easing in [Easing::Linear, Easing::EaseInOut, Easing::Smoothstep] {
+		assert!((easing.apply(0.5) - 0.5).abs() < 1e-6);
+	}
+}
+
+/// Cursor state of window without opening one.
+#[derive(Default)]
+struct Window {
+	pressed: RefCell<Vec<MouseButton>>,
+}
+
+impl Cursor for Window {
+	fn get_mouse_button(&self, button: MouseButton) -> Action {
+		if self.pressed.borrow().contains(&button) {
+			Action::Press
+		} else {
+			Action::Release
+		}
+	}
+	fn hide_cursor(&self, _hide: bool) {}
+	fn set_cursor_grab(&self, _grab: bool) {}
+	fn set_cursor_position(&self, _x: f64, _y: f64) {}
+}
+
+impl Window {
+	/// Dispatches event to trackball tracking mouse button state and returns whether it was
+	/// consumed.
+	fn send(&self, trackball: &mut Trackball, event: WindowEvent) -> bool {
+		if let WindowEvent::MouseButton(button, action, _modifiers) = event {
+			let mut pressed = self.pressed.borrow_mut();
+			pressed.retain(|&other| other != button);
+			if action == Action::Press {
+				pressed.push(button);
+			}
+		}
+		trackball.handle_event_with(self, &event, false)
+	}
+}
+
+#[test]
+fn multi_view_keeps_orientation_of_orthographic_views() {
+	let mut multi_view = MultiView::new(Point3::origin(), 10.0, Point2::new(800.0, 600.0));
+	let (window, view) = (Window::default(), &mut multi_view.views_mut()[0]);
+	view.update_dt(0.0);
+	let old = rotation(&view.frame);
+	window.send(
+		view,
+		WindowEvent::Key(Key::LShift, Action::Press, Modifiers::Shift),
+	);
+	for x in [200.0, 250.0, 300.0] {
+		window.send(view, WindowEvent::CursorPos(x, 150.0, Modifiers::Shift));
+	}
+	window.send(
+		view,
+		WindowEvent::Key(Key::LShift, Action::Release, Modifiers::empty()),
+	);
+	view.update_dt(0.0);
+	assert!(old.angle_to(&rotation(&view.frame)) < 1e-6);
+}
+
+/// Target after carrying on pan momentum for one second.
+fn pan_momentum(configure: impl Fn(&mut Trackball)) -> Point3<f32> {
+	let mut trackball = trackball();
+	trackball.set_pan_inertia(true);
+	configure(&mut trackball);
+	let inertia = trackball.pan_inertia.as_mut().unwrap();
+	inertia.record(Vector3::x());
+	inertia.sample(0.1);
+	inertia.release(None);
+	for _frame in 0..10 {
+		trackball.update_dt(0.1);
+	}
+	*trackball.frame.target()
+}
+
+#[test]
+fn own_damping_wins_regardless_of_call_order() {
+	let own_first = pan_momentum(|trackball| {
+		trackball.set_pan_damping(Some(0.1));
+		trackball.set_damping(0.5);
+	});
+	let own_last = pan_momentum(|trackball| {
+		trackball.set_damping(0.5);
+		trackball.set_pan_damping(Some(0.1));
+	});
+	let common = pan_momentum(|trackball| trackball.set_damping(0.5));
+	assert!((own_first - own_last).norm() < 1e-6);
+	assert!((own_first - common).norm() > 1e-2);
+}
+
+#[test]
+fn common_damping_applies_without_own_damping() {
+	let common = pan_momentum(|trackball| trackball.set_damping(0.1));
+	let own = pan_momentum(|trackball| trackball.set_pan_damping(Some(0.1)));
+	assert!((common - own).norm() < 1e-6);
+}
+
+#[test]
+fn view_changed_before_rendering() {
+	let (mut trackball, window) = (trackball(), Window::default());
+	trackball.update_dt(0.0);
+	assert!(!trackball.view_changed());
+	assert!(window.send(
+		&mut trackball,
+		WindowEvent::Scroll(0.0, 1.0, Modifiers::empty())
+	));
+	trackball.update_dt(0.0);
+	assert!(trackball.view_changed());
+	trackball.update_dt(0.0);
+	assert!(!trackball.view_changed());
+}
+
+#[test]
+fn gated_clicks_are_not_consumed() {
+	let (mut trackball, window) = (trackball(), Window::default());
+	trackball.input = Input::maya();
+	let (button, none) = (MouseButton::Button1, Modifiers::empty());
+	window.send(&mut trackball, WindowEvent::CursorPos(400.0, 300.0, none));
+	assert!(!window.send(
+		&mut trackball,
+		WindowEvent::MouseButton(button, Action::Press, none)
+	));
+	assert!(!window.send(
+		&mut trackball,
+		WindowEvent::MouseButton(button, Action::Release, none)
+	));
+	let alt = Modifiers::Alt;
+	assert!(window.send(
+		&mut trackball,
+		WindowEvent::MouseButton(button, Action::Press, alt)
+	));
+	assert!(window.send(
+		&mut trackball,
+		WindowEvent::MouseButton(button, Action::Release, alt)
+	));
+}
+
+#[test]
+fn re_engaged_drag_does_not_focus_on_release() {
+	let (mut trackball, window) = (trackball(), Window::default());
+	trackball.input = Input::maya();
+	let (button, alt) = (MouseButton::Button1, Modifiers::Alt);
+	window.send(&mut trackball, WindowEvent::CursorPos(400.0, 300.0, alt));
+	window.send(
+		&mut trackball,
+		WindowEvent::MouseButton(button, Action::Press, alt),
+	);
+	window.send(&mut trackball, WindowEvent::CursorPos(450.0, 300.0, alt));
+	window.send(
+		&mut trackball,
+		WindowEvent::CursorPos(460.0, 300.0, Modifiers::empty()),
+	);
+	window.send(&mut trackball, WindowEvent::CursorPos(600.0, 150.0, alt));
+	window.send(
+		&mut trackball,
+		WindowEvent::MouseButton(button, Action::Release, alt),
+	);
+	trackball.update_dt(0.0);
+	assert!(trackball.frame.target().coords.norm() < 1e-6);
+}
+
+/// Camera with bookmarks looking from `+z`, `+x`, and `-z` named `"front"`, `"right"`, and
+/// `"back"` in insertion order.
+fn bookmarked() -> Trackball {
+	let mut trackball = trackball();
+	for (name, view) in [
+		("front", StandardView::Front),
+		("right", StandardView::Right),
+		("back", StandardView::Back),
+	] {
+		trackball.set_standard_view(view);
+		trackball.save_bookmark(name);
+	}
+	trackball.set_standard_view(StandardView::Top);
+	trackball
+}
+
+/// Direction from target to eye.
+fn eye_dir(trackball: &Trackball) -> Vector3<f32> {
+	(trackball.frame.eye() - trackball.frame.target()).normalize()
+}
+
+#[test]
+fn bookmarks_cycle_forward_in_insertion_order() {
+	let mut trackball = bookmarked();
+	assert!(trackball.bookmarks().eq(["front", "right", "back"]));
+	for dir in [Vector3::z(), Vector3::x(), -Vector3::z(), Vector3::z()] {
+		trackball.next_bookmark();
+		assert!((eye_dir(&trackball) - dir).norm() < 1e-5);
+	}
+}
+
+#[test]
+fn bookmarks_cycle_backward_with_wraparound() {
+	let mut trackball = bookmarked();
+	for dir in [-Vector3::z(), Vector3::x(), Vector3::z(), -Vector3::z()] {
+		trackball.prev_bookmark();
+		assert!((eye_dir(&trackball) - dir).norm() < 1e-5);
+	}
+}
+
+#[test]
+fn empty_bookmarks_do_not_cycle() {
+	let mut trackball = trackball();
+	let frame = trackball.frame.clone();
+	trackball.next_bookmark();
+	trackball.prev_bookmark();
+	assert!(trackball.frame == frame);
+	let mut trackball = bookmarked();
+	for name in ["front", "right", "back"] {
+		assert!(trackball.remove_bookmark(name));
+	}
+	let frame = trackball.frame.clone();
+	trackball.next_bookmark();
+	assert!(trackball.frame == frame);
+}
+
+#[test]
+fn zooming_in_keeps_target_beyond_near_by_default() {
+	let mut trackball = trackball();
+	assert!(trackball.keep_beyond_near());
+	let (znear, _zfar) = trackball.scene.clip_planes(trackball.frame.distance());
+	trackball.frame.local_scale_around(1e-4, &Point3::origin());
+	trackball.update_dt(0.0);
+	assert!(trackball.frame.distance() > znear);
+	trackball.set_keep_beyond_near(false);
+	trackball.frame.local_scale_around(1e-4, &Point3::origin());
+	trackball.update_dt(0.0);
+	assert!(trackball.frame.distance() < znear);
+}
+
+#[test]
+fn gamepad_input_resets_idle_and_pauses_auto_rotation() {
+	let mut trackball = trackball();
+	trackball.set_auto_rotate(Some(1.0));
+	trackball.step(1.0);
+	let config = GamepadConfig::default();
+	trackball.handle_gamepad_config(GamepadInput::default(), 1.0, &config);
+	assert!(trackball.idle_time() > 0.0);
+	let input = GamepadInput {
+		right_trigger: 1.0,
+		..GamepadInput::default()
+	};
+	trackball.handle_gamepad_config(input, 1.0, &config);
+	assert!(trackball.idle_time() == 0.0);
+	let frame = trackball.frame.clone();
+	trackball.step(1.0);
+	assert!(trackball.frame == frame);
+}
+
+#[test]
+fn smooth_zoom_with_inertia_keeps_point_under_cursor() {
+	let mut trackball = trackball();
+	trackball.set_zoom_smoothing(Some(0.1));
+	trackball.set_zoom_inertia(true);
+	let pos = Point2::new(600.0, 150.0);
+	trackball.image.set_pos(pos);
+	let normal = trackball.frame.roll_axis().into_inner();
+	let point = trackball
+		.plane_point(pos, *trackball.frame.target(), normal)
+		.unwrap();
+	let distance = trackball.frame.distance();
+	for _frame in 0..10 {
+		trackball.apply_scroll(1.0);
+		trackball.update_dt(0.02);
+	}
+	for _frame in 0..10 {
+		trackball.update_dt(0.02);
+	}
+	assert!((trackball.frame.distance() - distance).abs() > 1e-1);
+	assert!((pixel(&trackball, &point) - pos).norm() < 1e-2);
+}
+
+#[test]
+fn inertia_keeps_motion_of_zero_frame_time() {
+	let mut inertia = Inertia::default();
+	inertia.record(Vector3::x());
+	inertia.sample(0.0);
+	inertia.sample(0.5);
+	inertia.release(None);
+	let vec = inertia.compute(0.5, 1.0).unwrap();
+	assert!(vec.x > 0.5);
+}
+
+#[test]
+fn frame_setters_apply_boundary_conditions_at_once() {
+	let mut trackball = trackball();
+	let (min, max) = (Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+	trackball.set_pan_bounds(Some((min, max)));
+	trackball.reset = trackball.frame.clone();
+	trackball.reset.slide(&Vector3::new(5.0, 0.0, 0.0));
+	trackball.reset_frame();
+	assert!((trackball.frame.target().x - 1.0).abs() < 1e-5);
+	trackball.set_spherical(0.0, 0.0, 1e-3);
+	assert!(trackball.frame.distance() > trackball.scene.clip_planes(1e-3).0);
+}
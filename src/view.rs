@@ -0,0 +1,49 @@
+use kiss3d::nalgebra::Vector3;
+
+/// Standard view looking down a world axis at the target.
+///
+/// Axes are given for a world-up axis of positive y-axis, otherwise they are rotated accordingly.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StandardView {
+	/// Looks from positive z-axis.
+	Front,
+	/// Looks from negative z-axis.
+	Back,
+	/// Looks from negative x-axis.
+	Left,
+	/// Looks from positive x-axis.
+	Right,
+	/// Looks from positive y-axis with front at the bottom.
+	Top,
+	/// Looks from negative y-axis with front at the top.
+	Bottom,
+	/// Looks from diagonal of positive axes.
+	Iso,
+}
+
+impl StandardView {
+	/// All standard views in order of declaration.
+	pub const ALL: [Self; 7] = [
+		Self::Front,
+		Self::Back,
+		Self::Left,
+		Self::Right,
+		Self::Top,
+		Self::Bottom,
+		Self::Iso,
+	];
+
+	/// Direction from target to eye and up vector for a world-up axis of positive y-axis.
+	#[must_use]
+	pub fn eye_and_up(self) -> (Vector3<f32>, Vector3<f32>) {
+		match self {
+			Self::Front => (Vector3::z(), Vector3::y()),
+			Self::Back => (-Vector3::z(), Vector3::y()),
+			Self::Left => (-Vector3::x(), Vector3::y()),
+			Self::Right => (Vector3::x(), Vector3::y()),
+			Self::Top => (Vector3::y(), -Vector3::z()),
+			Self::Bottom => (-Vector3::y(), Vector3::z()),
+			Self::Iso => (Vector3::repeat(1.0).normalize(), Vector3::y()),
+		}
+	}
+}